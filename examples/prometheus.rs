@@ -0,0 +1,14 @@
+use std::env;
+
+use psutil::prometheus::Exporter;
+
+fn main() {
+	let addr = env::args()
+		.nth(1)
+		.unwrap_or_else(|| String::from("127.0.0.1:9100"));
+
+	println!("Serving metrics on http://{}/metrics", addr);
+	Exporter::new()
+		.serve_with(addr, |err| eprintln!("Failed to serve a scrape: {}", err))
+		.unwrap();
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TcpConnectionStatus {
 	Established,
 	SynSent,
@@ -6,9 +6,93 @@ use std::time::Duration;
 
 use derive_more::{Add, Sub, Sum};
 
+#[cfg(target_os = "linux")]
+use crate::disk::disk_io_counters_per_disk;
 use crate::disk::disk_io_counters_per_partition;
 use crate::{Bytes, Count, Result};
 
+/// An `Option` that can be added and subtracted, for counters that older kernels lack.
+///
+/// The result is `None` if either side is `None`.
+#[cfg(target_os = "linux")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde", transparent))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Optional<T>(pub(crate) Option<T>);
+
+#[cfg(target_os = "linux")]
+impl<T> Optional<T> {
+	fn zip_with<F>(self, other: Optional<T>, f: F) -> Optional<T>
+	where
+		F: FnOnce(T, T) -> T,
+	{
+		match (self.0, other.0) {
+			(Some(lhs), Some(rhs)) => Optional(Some(f(lhs, rhs))),
+			_ => Optional(None),
+		}
+	}
+}
+
+#[cfg(target_os = "linux")]
+impl<T: std::ops::Add<Output = T>> std::ops::Add for Optional<T> {
+	type Output = Optional<T>;
+
+	fn add(self, other: Optional<T>) -> Optional<T> {
+		self.zip_with(other, std::ops::Add::add)
+	}
+}
+
+#[cfg(target_os = "linux")]
+impl<T: std::ops::Sub<Output = T>> std::ops::Sub for Optional<T> {
+	type Output = Optional<T>;
+
+	fn sub(self, other: Optional<T>) -> Optional<T> {
+		self.zip_with(other, std::ops::Sub::sub)
+	}
+}
+
+#[cfg(target_os = "linux")]
+impl<T: std::ops::Add<Output = T> + std::iter::Sum<T>> std::iter::Sum for Optional<T> {
+	fn sum<I: Iterator<Item = Optional<T>>>(iter: I) -> Optional<T> {
+		iter.fold(Optional(Some(std::iter::empty().sum())), std::ops::Add::add)
+	}
+}
+
+/// A current value rather than a counter, such as a queue depth.
+///
+/// Adding sums the values, e.g. over several disks, but subtracting keeps the left-hand side, so
+/// the difference of two samples holds the value of the newer one.
+#[cfg(target_os = "linux")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde", transparent))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Gauge<T>(pub(crate) T);
+
+#[cfg(target_os = "linux")]
+impl<T: std::ops::Add<Output = T>> std::ops::Add for Gauge<T> {
+	type Output = Gauge<T>;
+
+	fn add(self, other: Gauge<T>) -> Gauge<T> {
+		Gauge(self.0 + other.0)
+	}
+}
+
+#[cfg(target_os = "linux")]
+impl<T> std::ops::Sub for Gauge<T> {
+	type Output = Gauge<T>;
+
+	fn sub(self, _other: Gauge<T>) -> Gauge<T> {
+		self
+	}
+}
+
+#[cfg(target_os = "linux")]
+impl<T: std::iter::Sum<T>> std::iter::Sum for Gauge<T> {
+	fn sum<I: Iterator<Item = Gauge<T>>>(iter: I) -> Gauge<T> {
+		Gauge(iter.map(|gauge| gauge.0).sum())
+	}
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
 #[derive(Clone, Debug, Add, Sum, Default, Sub)]
@@ -30,6 +114,22 @@ pub struct DiskIoCounters {
 	pub(crate) read_merged_count: Count,
 	#[cfg(target_os = "linux")]
 	pub(crate) write_merged_count: Count,
+	#[cfg(target_os = "linux")]
+	pub(crate) in_flight: Gauge<Count>,
+	#[cfg(target_os = "linux")]
+	pub(crate) weighted_io_time: Duration,
+	#[cfg(target_os = "linux")]
+	pub(crate) discard_count: Optional<Count>,
+	#[cfg(target_os = "linux")]
+	pub(crate) discard_merged_count: Optional<Count>,
+	#[cfg(target_os = "linux")]
+	pub(crate) discard_bytes: Optional<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) discard_time: Optional<Duration>,
+	#[cfg(target_os = "linux")]
+	pub(crate) flush_count: Optional<Count>,
+	#[cfg(target_os = "linux")]
+	pub(crate) flush_time: Optional<Duration>,
 }
 
 impl DiskIoCounters {
@@ -52,6 +152,76 @@ impl DiskIoCounters {
 	pub fn write_bytes(&self) -> Bytes {
 		self.write_bytes
 	}
+
+	/// Like `Sub`, but clamps each field at zero instead of overflowing when a counter was reset,
+	/// e.g. because a device was removed and added again.
+	#[cfg(any(feature = "monitor", target_os = "linux"))]
+	pub(crate) fn saturating_sub(&self, other: &DiskIoCounters) -> DiskIoCounters {
+		DiskIoCounters {
+			read_count: self.read_count.saturating_sub(other.read_count),
+			write_count: self.write_count.saturating_sub(other.write_count),
+			read_bytes: self.read_bytes.saturating_sub(other.read_bytes),
+			write_bytes: self.write_bytes.saturating_sub(other.write_bytes),
+
+			#[cfg(not(any(target_os = "netbsd", target_os = "openbsd")))]
+			read_time: self
+				.read_time
+				.checked_sub(other.read_time)
+				.unwrap_or_default(),
+			#[cfg(not(any(target_os = "netbsd", target_os = "openbsd")))]
+			write_time: self
+				.write_time
+				.checked_sub(other.write_time)
+				.unwrap_or_default(),
+
+			#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+			busy_time: self
+				.busy_time
+				.checked_sub(other.busy_time)
+				.unwrap_or_default(),
+
+			#[cfg(target_os = "linux")]
+			read_merged_count: self
+				.read_merged_count
+				.saturating_sub(other.read_merged_count),
+			#[cfg(target_os = "linux")]
+			write_merged_count: self
+				.write_merged_count
+				.saturating_sub(other.write_merged_count),
+			// not a counter
+			#[cfg(target_os = "linux")]
+			in_flight: self.in_flight,
+			#[cfg(target_os = "linux")]
+			weighted_io_time: self
+				.weighted_io_time
+				.checked_sub(other.weighted_io_time)
+				.unwrap_or_default(),
+			#[cfg(target_os = "linux")]
+			discard_count: self
+				.discard_count
+				.zip_with(other.discard_count, u64::saturating_sub),
+			#[cfg(target_os = "linux")]
+			discard_merged_count: self
+				.discard_merged_count
+				.zip_with(other.discard_merged_count, u64::saturating_sub),
+			#[cfg(target_os = "linux")]
+			discard_bytes: self
+				.discard_bytes
+				.zip_with(other.discard_bytes, u64::saturating_sub),
+			#[cfg(target_os = "linux")]
+			discard_time: self.discard_time.zip_with(other.discard_time, |lhs, rhs| {
+				lhs.checked_sub(rhs).unwrap_or_default()
+			}),
+			#[cfg(target_os = "linux")]
+			flush_count: self
+				.flush_count
+				.zip_with(other.flush_count, u64::saturating_sub),
+			#[cfg(target_os = "linux")]
+			flush_time: self.flush_time.zip_with(other.flush_time, |lhs, rhs| {
+				lhs.checked_sub(rhs).unwrap_or_default()
+			}),
+		}
+	}
 }
 
 fn nowrap(prev: u64, current: u64, corrected: u64) -> u64 {
@@ -62,6 +232,36 @@ fn nowrap(prev: u64, current: u64, corrected: u64) -> u64 {
 	}
 }
 
+#[cfg(target_os = "linux")]
+fn nowrap_optional(
+	prev: Optional<u64>,
+	current: Optional<u64>,
+	corrected: Optional<u64>,
+) -> Optional<u64> {
+	match (prev.0, current.0, corrected.0) {
+		(Some(prev), Some(current), Some(corrected)) => {
+			Optional(Some(nowrap(prev, current, corrected)))
+		}
+		// not supported by the kernel
+		_ => current,
+	}
+}
+
+#[cfg(target_os = "linux")]
+fn nowrap_optional_duration(
+	prev: Optional<Duration>,
+	current: Optional<Duration>,
+	corrected: Optional<Duration>,
+) -> Optional<Duration> {
+	let millis = |duration: Optional<Duration>| Optional(duration.0.map(|d| d.as_millis() as u64));
+
+	Optional(
+		nowrap_optional(millis(prev), millis(current), millis(corrected))
+			.0
+			.map(Duration::from_millis),
+	)
+}
+
 fn nowrap_struct(
 	prev: &DiskIoCounters,
 	current: &DiskIoCounters,
@@ -105,6 +305,47 @@ fn nowrap_struct(
 			current.write_merged_count,
 			corrected.write_merged_count,
 		),
+		// not a counter
+		#[cfg(target_os = "linux")]
+		in_flight: current.in_flight,
+		#[cfg(target_os = "linux")]
+		weighted_io_time: Duration::from_millis(nowrap(
+			prev.weighted_io_time.as_millis() as u64,
+			current.weighted_io_time.as_millis() as u64,
+			corrected.weighted_io_time.as_millis() as u64,
+		)),
+		#[cfg(target_os = "linux")]
+		discard_count: nowrap_optional(
+			prev.discard_count,
+			current.discard_count,
+			corrected.discard_count,
+		),
+		#[cfg(target_os = "linux")]
+		discard_merged_count: nowrap_optional(
+			prev.discard_merged_count,
+			current.discard_merged_count,
+			corrected.discard_merged_count,
+		),
+		#[cfg(target_os = "linux")]
+		discard_bytes: nowrap_optional(
+			prev.discard_bytes,
+			current.discard_bytes,
+			corrected.discard_bytes,
+		),
+		#[cfg(target_os = "linux")]
+		discard_time: nowrap_optional_duration(
+			prev.discard_time,
+			current.discard_time,
+			corrected.discard_time,
+		),
+		#[cfg(target_os = "linux")]
+		flush_count: nowrap_optional(prev.flush_count, current.flush_count, corrected.flush_count),
+		#[cfg(target_os = "linux")]
+		flush_time: nowrap_optional_duration(
+			prev.flush_time,
+			current.flush_time,
+			corrected.flush_time,
+		),
 	}
 }
 
@@ -140,6 +381,7 @@ pub struct DiskIoCountersCollector {
 }
 
 impl DiskIoCountersCollector {
+	/// Sums the counters of every device, including partitions as well as the disks they are on.
 	pub fn disk_io_counters(&mut self) -> Result<DiskIoCounters> {
 		let sum = self.disk_io_counters_per_partition()?.into_values().sum();
 
@@ -162,4 +404,16 @@ impl DiskIoCountersCollector {
 
 		Ok(corrected_counters)
 	}
+
+	/// Like `disk_io_counters_per_partition`, but only whole disks, so the values can be summed
+	/// without counting I/O twice.
+	///
+	/// Device mapper, md and loop devices are left out, since their I/O also shows up on the disks
+	/// they are stacked on.
+	///
+	/// New method, not in Python psutil.
+	#[cfg(target_os = "linux")]
+	pub fn disk_io_counters_per_disk(&mut self) -> Result<HashMap<String, DiskIoCounters>> {
+		disk_io_counters_per_disk(self.disk_io_counters_per_partition()?)
+	}
 }
@@ -7,8 +7,8 @@ use std::str::FromStr;
 
 /// Known filesystems.
 ///
-/// All physical filesystems should have their own enum element
-/// and all virtual filesystems will go into the `Other` element.
+/// Common filesystems have their own enum element and all others go into the `Other` element.
+/// See [`FileSystem::kind`] to tell them apart.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
 #[non_exhaustive]
@@ -68,19 +68,75 @@ pub enum FileSystem {
 	/// APFS (https://en.wikipedia.org/wiki/Apple_File_System)
 	Apfs,
 
-	// TODO: Should it be considered as a physical FS?
-	/// FUSE (https://en.wikipedia.org/wiki/Filesystem_in_Userspace)
+	/// Bcachefs (https://en.wikipedia.org/wiki/Bcachefs)
+	Bcachefs,
+
+	/// SquashFS (https://en.wikipedia.org/wiki/SquashFS)
+	Squashfs,
+
+	/// EROFS (https://en.wikipedia.org/wiki/EROFS)
+	Erofs,
+
+	/// FUSE (https://en.wikipedia.org/wiki/Filesystem_in_Userspace) backed by a block device
 	FuseBlk,
 
-	// TODO: Extend list
+	/// OverlayFS (https://en.wikipedia.org/wiki/OverlayFS)
+	Overlay,
+
+	/// tmpfs (https://en.wikipedia.org/wiki/Tmpfs)
+	Tmpfs,
+
+	/// NFS version 2 and 3 (https://en.wikipedia.org/wiki/Network_File_System)
+	Nfs,
+
+	/// NFS version 4 (https://en.wikipedia.org/wiki/Network_File_System)
+	Nfs4,
+
+	/// CIFS and SMB (https://en.wikipedia.org/wiki/Server_Message_Block)
+	Cifs,
+
+	/// CephFS (https://en.wikipedia.org/wiki/Ceph_(software))
+	Ceph,
+
 	/// Some unspecified filesystem.
 	Other(String),
 }
 
+/// Where the data of a filesystem is stored.
+///
+/// New enum, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FileSystemKind {
+	/// On a local block device, e.g. ext4 or squashfs.
+	Local,
+
+	/// On another machine, e.g. NFS or CIFS.
+	Network,
+
+	/// On top of other filesystems, e.g. overlay.
+	Virtual,
+
+	/// In memory, e.g. tmpfs.
+	Memory,
+
+	/// Kernel interfaces without any storage, e.g. proc or sysfs.
+	Pseudo,
+
+	/// Implemented in userspace, whatever the storage.
+	Fuse,
+
+	/// Not known to this crate.
+	Unknown,
+}
+
 impl FileSystem {
 	/// Checks if filesystem is used for a physical devices
 	pub fn is_physical(&self) -> bool {
-		!matches!(self, FileSystem::Other(..))
+		// block device backed FUSE filesystems like ntfs-3g stand in for in-kernel ones
+		self.kind() == FileSystemKind::Local || *self == FileSystem::FuseBlk
 	}
 
 	/// Checks if filesystem is used for a virtual devices (such as `tmpfs` or `smb` mounts)
@@ -88,6 +144,39 @@ impl FileSystem {
 		!self.is_physical()
 	}
 
+	/// Classifies the filesystem by where its data is stored.
+	///
+	/// Well known network filesystems implemented in userspace, like sshfs, are `Network`, other
+	/// ones are `Fuse`.
+	///
+	/// New method, not in Python psutil.
+	pub fn kind(&self) -> FileSystemKind {
+		match self {
+			FileSystem::Overlay => FileSystemKind::Virtual,
+			FileSystem::Tmpfs => FileSystemKind::Memory,
+			FileSystem::Nfs | FileSystem::Nfs4 | FileSystem::Cifs | FileSystem::Ceph => {
+				FileSystemKind::Network
+			}
+			FileSystem::FuseBlk => FileSystemKind::Fuse,
+			FileSystem::Other(name) => match name.as_str() {
+				"9p" | "afpfs" | "afs" | "glusterfs" | "lustre" | "ncpfs" | "smb3" | "smbfs"
+				| "webdav" => FileSystemKind::Network,
+				"aufs" | "ecryptfs" | "unionfs" => FileSystemKind::Virtual,
+				"devtmpfs" | "hugetlbfs" | "ramfs" | "shm" => FileSystemKind::Memory,
+				"autofs" | "binfmt_misc" | "bpf" | "cgroup" | "cgroup2" | "configfs"
+				| "debugfs" | "devfs" | "devpts" | "efivarfs" | "fdescfs" | "fusectl"
+				| "mqueue" | "nsfs" | "proc" | "procfs" | "pstore" | "rpc_pipefs"
+				| "securityfs" | "selinuxfs" | "sysfs" | "tracefs" => FileSystemKind::Pseudo,
+				"fuse.ceph-fuse" | "fuse.gcsfuse" | "fuse.glusterfs" | "fuse.rclone"
+				| "fuse.s3fs" | "fuse.sshfs" => FileSystemKind::Network,
+				"fuse" => FileSystemKind::Fuse,
+				_ if name.starts_with("fuse.") => FileSystemKind::Fuse,
+				_ => FileSystemKind::Unknown,
+			},
+			_ => FileSystemKind::Local,
+		}
+	}
+
 	/// Returns a string identifying this filesystem.
 	pub fn as_str(&self) -> &str {
 		match self {
@@ -110,6 +199,15 @@ impl FileSystem {
 			FileSystem::Nilfs => "nilfs",
 			FileSystem::Xfs => "xfs",
 			FileSystem::Apfs => "apfs",
+			FileSystem::Bcachefs => "bcachefs",
+			FileSystem::Squashfs => "squashfs",
+			FileSystem::Erofs => "erofs",
+			FileSystem::Overlay => "overlay",
+			FileSystem::Tmpfs => "tmpfs",
+			FileSystem::Nfs => "nfs",
+			FileSystem::Nfs4 => "nfs4",
+			FileSystem::Cifs => "cifs",
+			FileSystem::Ceph => "ceph",
 			FileSystem::Other(string) => string.as_str(),
 		}
 	}
@@ -138,9 +236,44 @@ impl FromStr for FileSystem {
 			_ if s.eq_ignore_ascii_case("nilfs") => Ok(FileSystem::Nilfs),
 			_ if s.eq_ignore_ascii_case("xfs") => Ok(FileSystem::Xfs),
 			_ if s.eq_ignore_ascii_case("apfs") => Ok(FileSystem::Apfs),
+			_ if s.eq_ignore_ascii_case("bcachefs") => Ok(FileSystem::Bcachefs),
+			_ if s.eq_ignore_ascii_case("squashfs") => Ok(FileSystem::Squashfs),
+			_ if s.eq_ignore_ascii_case("erofs") => Ok(FileSystem::Erofs),
 
 			_ if s.eq_ignore_ascii_case("fuseblk") => Ok(FileSystem::FuseBlk),
+			_ if s.eq_ignore_ascii_case("overlay") => Ok(FileSystem::Overlay),
+			_ if s.eq_ignore_ascii_case("tmpfs") => Ok(FileSystem::Tmpfs),
+			_ if s.eq_ignore_ascii_case("nfs") => Ok(FileSystem::Nfs),
+			_ if s.eq_ignore_ascii_case("nfs4") => Ok(FileSystem::Nfs4),
+			_ if s.eq_ignore_ascii_case("cifs") => Ok(FileSystem::Cifs),
+			_ if s.eq_ignore_ascii_case("ceph") => Ok(FileSystem::Ceph),
 			_ => Ok(FileSystem::Other(s.to_string())),
 		}
 	}
 }
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_filesystem_kind() {
+		let kind = |name: &str| FileSystem::from_str(name).unwrap().kind();
+
+		assert_eq!(kind("ext4"), FileSystemKind::Local);
+		assert_eq!(kind("squashfs"), FileSystemKind::Local);
+		assert_eq!(kind("nfs4"), FileSystemKind::Network);
+		assert_eq!(kind("smb3"), FileSystemKind::Network);
+		assert_eq!(kind("overlay"), FileSystemKind::Virtual);
+		assert_eq!(kind("tmpfs"), FileSystemKind::Memory);
+		assert_eq!(kind("cgroup2"), FileSystemKind::Pseudo);
+		assert_eq!(kind("fuse.sshfs"), FileSystemKind::Network);
+		assert_eq!(kind("fuse.lxcfs"), FileSystemKind::Fuse);
+		assert_eq!(kind("somethingfs"), FileSystemKind::Unknown);
+		assert!(!FileSystem::from_str("somethingfs").unwrap().is_physical());
+
+		assert!(FileSystem::FuseBlk.is_physical());
+		assert!(!FileSystem::Tmpfs.is_physical());
+		assert_eq!(FileSystem::from_str("ceph").unwrap().as_str(), "ceph");
+	}
+}
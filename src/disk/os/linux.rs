@@ -1,7 +1,7 @@
 use std::time::Duration;
 
-use crate::disk::DiskIoCounters;
-use crate::Count;
+use crate::disk::{DiskIoCounters, MountInfo, Partition};
+use crate::{Bytes, Count};
 
 pub trait DiskIoCountersExt {
 	/// Time spent reading from disk.
@@ -18,6 +18,33 @@ pub trait DiskIoCountersExt {
 
 	/// Number of merged writes.
 	fn write_merged_count(&self) -> Count;
+
+	/// Number of I/Os currently in progress.
+	///
+	/// This is the current queue depth rather than a counter, so the difference of two
+	/// `DiskIoCounters` holds the value of the newer one.
+	fn in_flight(&self) -> Count;
+
+	/// Time spent doing I/Os multiplied by the number of I/Os in progress.
+	fn weighted_io_time(&self) -> Duration;
+
+	/// Number of discards, `None` before Linux 4.18.
+	fn discard_count(&self) -> Option<Count>;
+
+	/// Number of merged discards, `None` before Linux 4.18.
+	fn discard_merged_count(&self) -> Option<Count>;
+
+	/// Number of bytes discarded, `None` before Linux 4.18.
+	fn discard_bytes(&self) -> Option<Bytes>;
+
+	/// Time spent discarding, `None` before Linux 4.18.
+	fn discard_time(&self) -> Option<Duration>;
+
+	/// Number of flushes, `None` before Linux 5.5.
+	fn flush_count(&self) -> Option<Count>;
+
+	/// Time spent flushing, `None` before Linux 5.5.
+	fn flush_time(&self) -> Option<Duration>;
 }
 
 impl DiskIoCountersExt for DiskIoCounters {
@@ -40,4 +67,47 @@ impl DiskIoCountersExt for DiskIoCounters {
 	fn write_merged_count(&self) -> Count {
 		self.write_merged_count
 	}
+
+	fn in_flight(&self) -> Count {
+		self.in_flight.0
+	}
+
+	fn weighted_io_time(&self) -> Duration {
+		self.weighted_io_time
+	}
+
+	fn discard_count(&self) -> Option<Count> {
+		self.discard_count.0
+	}
+
+	fn discard_merged_count(&self) -> Option<Count> {
+		self.discard_merged_count.0
+	}
+
+	fn discard_bytes(&self) -> Option<Bytes> {
+		self.discard_bytes.0
+	}
+
+	fn discard_time(&self) -> Option<Duration> {
+		self.discard_time.0
+	}
+
+	fn flush_count(&self) -> Option<Count> {
+		self.flush_count.0
+	}
+
+	fn flush_time(&self) -> Option<Duration> {
+		self.flush_time.0
+	}
+}
+
+pub trait PartitionExt {
+	/// `None` before Linux 2.6.26, which lacks `/proc/self/mountinfo`.
+	fn mount_info(&self) -> Option<&MountInfo>;
+}
+
+impl PartitionExt for Partition {
+	fn mount_info(&self) -> Option<&MountInfo> {
+		self.mount_info.as_ref()
+	}
 }
@@ -3,17 +3,22 @@ use serde::{Deserialize, Serialize};
 
 use std::path::{Path, PathBuf};
 
-use crate::disk::{partitions, FileSystem};
+#[cfg(target_os = "linux")]
+use crate::disk::MountInfo;
+use crate::disk::{partitions, FileSystem, FileSystemKind};
 use crate::Result;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Partition {
 	pub(crate) device: String,
 	pub(crate) mountpoint: PathBuf,
 	pub(crate) filesystem: FileSystem,
 	pub(crate) mount_options: String,
+
+	#[cfg(target_os = "linux")]
+	pub(crate) mount_info: Option<MountInfo>,
 }
 
 impl Partition {
@@ -42,3 +47,13 @@ pub fn partitions_physical() -> Result<Vec<Partition>> {
 		.filter(|partition| partition.filesystem.is_physical())
 		.collect())
 }
+
+/// Partitions whose data is stored on another machine, see [`FileSystemKind::Network`].
+///
+/// New function, not in Python psutil.
+pub fn partitions_network() -> Result<Vec<Partition>> {
+	Ok(partitions()?
+		.into_iter()
+		.filter(|partition| partition.filesystem.kind() == FileSystemKind::Network)
+		.collect())
+}
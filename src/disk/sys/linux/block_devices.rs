@@ -0,0 +1,421 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::disk::DISK_SECTOR_SIZE;
+use crate::{read_dir, read_file, Bytes, Error, Result};
+
+const SYS_BLOCK: &str = "/sys/block";
+
+/// A whole disk or a partition from `/sys/block`.
+///
+/// Partitions share the queue, and therefore the sector sizes, rotational flag and scheduler,
+/// of their parent disk.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockDevice {
+	pub(crate) name: String,
+	pub(crate) parent: Option<String>,
+	pub(crate) size: Bytes,
+	pub(crate) logical_sector_size: Bytes,
+	pub(crate) physical_sector_size: Bytes,
+	pub(crate) rotational: bool,
+	pub(crate) removable: bool,
+	pub(crate) model: Option<String>,
+	pub(crate) vendor: Option<String>,
+	pub(crate) serial: Option<String>,
+	pub(crate) scheduler: Option<String>,
+	pub(crate) dm_name: Option<String>,
+	pub(crate) dm_uuid: Option<String>,
+	pub(crate) slaves: Vec<String>,
+	pub(crate) holders: Vec<String>,
+}
+
+impl BlockDevice {
+	/// Kernel name, e.g. `sda` or `nvme0n1p1`.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Name of the whole disk a partition belongs to, `None` for whole disks.
+	pub fn parent(&self) -> Option<&str> {
+		self.parent.as_deref()
+	}
+
+	pub fn is_partition(&self) -> bool {
+		self.parent.is_some()
+	}
+
+	pub fn size(&self) -> Bytes {
+		self.size
+	}
+
+	/// Smallest unit the device can address.
+	pub fn logical_sector_size(&self) -> Bytes {
+		self.logical_sector_size
+	}
+
+	/// Smallest unit the device can write without a read-modify-write cycle.
+	pub fn physical_sector_size(&self) -> Bytes {
+		self.physical_sector_size
+	}
+
+	/// Whether the device is a spinning disk. Virtual devices often claim to be rotational.
+	pub fn rotational(&self) -> bool {
+		self.rotational
+	}
+
+	pub fn removable(&self) -> bool {
+		self.removable
+	}
+
+	/// Only reported by some drivers, e.g. SCSI, SATA and NVMe.
+	pub fn model(&self) -> Option<&str> {
+		self.model.as_deref()
+	}
+
+	/// Only reported by some drivers, e.g. SCSI and SATA.
+	pub fn vendor(&self) -> Option<&str> {
+		self.vendor.as_deref()
+	}
+
+	/// Only reported by some drivers, e.g. NVMe and virtio.
+	pub fn serial(&self) -> Option<&str> {
+		self.serial.as_deref()
+	}
+
+	/// The active I/O scheduler, e.g. `mq-deadline` or `none`.
+	pub fn scheduler(&self) -> Option<&str> {
+		self.scheduler.as_deref()
+	}
+
+	/// Name of a device-mapper device, e.g. `vg0-root` for `dm-0`.
+	pub fn dm_name(&self) -> Option<&str> {
+		self.dm_name.as_deref()
+	}
+
+	/// UUID of a device-mapper device, prefixed by the subsystem that created it like `LVM-` or
+	/// `mpath-`.
+	pub fn dm_uuid(&self) -> Option<&str> {
+		self.dm_uuid.as_deref()
+	}
+
+	/// Volume group and logical volume of an LVM device.
+	pub fn lvm_volume(&self) -> Option<LvmVolume> {
+		if !self.dm_uuid()?.starts_with("LVM-") {
+			return None;
+		}
+
+		parse_lvm_name(self.dm_name()?)
+	}
+
+	/// Whether the device is a device-mapper multipath device.
+	pub fn is_multipath(&self) -> bool {
+		matches!(self.dm_uuid(), Some(uuid) if uuid.starts_with("mpath-"))
+	}
+
+	/// Devices this one is built on, e.g. the members of a RAID array or the physical volumes of
+	/// an LVM volume.
+	pub fn slaves(&self) -> &[String] {
+		&self.slaves
+	}
+
+	/// Devices built on this one, the reverse of [`slaves`](BlockDevice::slaves).
+	pub fn holders(&self) -> &[String] {
+		&self.holders
+	}
+}
+
+/// An LVM logical volume.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LvmVolume {
+	pub(crate) volume_group: String,
+	pub(crate) logical_volume: String,
+	pub(crate) layer: Option<String>,
+}
+
+impl LvmVolume {
+	pub fn volume_group(&self) -> &str {
+		&self.volume_group
+	}
+
+	pub fn logical_volume(&self) -> &str {
+		&self.logical_volume
+	}
+
+	/// Internal layer of the logical volume, e.g. `tpool` of a thin pool or `cow` of a snapshot.
+	/// `None` for the logical volume itself.
+	pub fn layer(&self) -> Option<&str> {
+		self.layer.as_deref()
+	}
+}
+
+/// Splits device-mapper names like `vg--data-lv--home` or `vg0-pool-tpool`, where hyphens within
+/// the volume group and logical volume names are doubled and an optional third part is the layer.
+fn parse_lvm_name(name: &str) -> Option<LvmVolume> {
+	let bytes = name.as_bytes();
+	let mut parts = Vec::new();
+	let mut start = 0;
+	let mut i = 0;
+
+	while i < bytes.len() {
+		if bytes[i] == b'-' {
+			if bytes.get(i + 1) == Some(&b'-') {
+				i += 2;
+				continue;
+			}
+
+			parts.push(&name[start..i]);
+			start = i + 1;
+		}
+		i += 1;
+	}
+	parts.push(&name[start..]);
+
+	let unescape = |part: &str| part.replace("--", "-");
+	match parts.as_slice() {
+		[volume_group, logical_volume] => Some(LvmVolume {
+			volume_group: unescape(volume_group),
+			logical_volume: unescape(logical_volume),
+			layer: None,
+		}),
+		[volume_group, logical_volume, layer] => Some(LvmVolume {
+			volume_group: unescape(volume_group),
+			logical_volume: unescape(logical_volume),
+			layer: Some(unescape(layer)),
+		}),
+		_ => None,
+	}
+}
+
+/// Reads an optional attribute, `None` if the file is missing or empty.
+fn read_attribute(path: &Path) -> Result<Option<String>> {
+	match read_file(path) {
+		Ok(contents) => {
+			let contents = contents.trim();
+
+			Ok(if contents.is_empty() {
+				None
+			} else {
+				Some(contents.to_string())
+			})
+		}
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => Ok(None),
+		Err(err) => Err(err),
+	}
+}
+
+fn read_u64(path: &Path) -> Result<u64> {
+	let contents = read_file(path)?;
+
+	contents.trim().parse().map_err(|err| Error::ParseInt {
+		path: path.into(),
+		contents,
+		source: err,
+	})
+}
+
+/// Parses lines like `none [mq-deadline] kyber bfq`. Devices without a choice only print `none`.
+fn parse_scheduler(contents: &str) -> Option<String> {
+	let mut schedulers = contents.split_whitespace();
+	let selected = schedulers
+		.clone()
+		.find_map(|scheduler| scheduler.strip_prefix('[')?.strip_suffix(']'));
+
+	match (selected, schedulers.next(), schedulers.next()) {
+		(Some(selected), _, _) => Some(selected.to_string()),
+		(None, Some(only), None) => Some(only.to_string()),
+		_ => None,
+	}
+}
+
+/// The attributes a partition inherits from its disk.
+struct Queue {
+	logical_sector_size: Bytes,
+	physical_sector_size: Bytes,
+	rotational: bool,
+	removable: bool,
+	model: Option<String>,
+	vendor: Option<String>,
+	serial: Option<String>,
+	scheduler: Option<String>,
+}
+
+fn read_queue(path: &Path) -> Result<Queue> {
+	let queue = path.join("queue");
+	let device = path.join("device");
+
+	Ok(Queue {
+		logical_sector_size: read_u64(&queue.join("logical_block_size"))?,
+		physical_sector_size: read_u64(&queue.join("physical_block_size"))?,
+		rotational: read_u64(&queue.join("rotational"))? == 1,
+		removable: read_u64(&path.join("removable"))? == 1,
+		model: read_attribute(&device.join("model"))?,
+		vendor: read_attribute(&device.join("vendor"))?,
+		serial: read_attribute(&device.join("serial"))?,
+		scheduler: read_attribute(&queue.join("scheduler"))?
+			.as_deref()
+			.and_then(parse_scheduler),
+	})
+}
+
+/// Names of the devices linked from `slaves` or `holders`, empty if the directory is missing.
+fn read_links(path: &Path) -> Result<Vec<String>> {
+	match sorted_entries(path) {
+		Ok(entries) => Ok(entries.into_iter().map(|(name, _)| name).collect()),
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => {
+			Ok(Vec::new())
+		}
+		Err(err) => Err(err),
+	}
+}
+
+fn block_device(
+	name: String,
+	parent: Option<String>,
+	path: &Path,
+	queue: &Queue,
+) -> Result<BlockDevice> {
+	Ok(BlockDevice {
+		name,
+		parent,
+		// always in 512 byte units, independent of the sector size
+		size: read_u64(&path.join("size"))? * DISK_SECTOR_SIZE,
+		logical_sector_size: queue.logical_sector_size,
+		physical_sector_size: queue.physical_sector_size,
+		rotational: queue.rotational,
+		removable: queue.removable,
+		model: queue.model.clone(),
+		vendor: queue.vendor.clone(),
+		serial: queue.serial.clone(),
+		scheduler: queue.scheduler.clone(),
+		dm_name: read_attribute(&path.join("dm/name"))?,
+		dm_uuid: read_attribute(&path.join("dm/uuid"))?,
+		slaves: read_links(&path.join("slaves"))?,
+		holders: read_links(&path.join("holders"))?,
+	})
+}
+
+/// Entries of a directory by name, skipping names that are not valid UTF-8.
+fn sorted_entries(path: &Path) -> Result<Vec<(String, PathBuf)>> {
+	let mut entries: Vec<_> = read_dir(path)?
+		.iter()
+		.filter_map(|entry| Some((entry.file_name().into_string().ok()?, entry.path())))
+		.collect();
+	entries.sort();
+
+	Ok(entries)
+}
+
+/// Names of the whole disks in `/sys/block` that don't sit on top of other devices.
+///
+/// Device mapper and md devices, which have `slaves`, and loop devices, which are backed by a
+/// file, are left out since their I/O is also counted on the disks below them.
+pub(crate) fn disk_names() -> Result<Vec<String>> {
+	let mut names = Vec::new();
+
+	for (name, path) in sorted_entries(Path::new(SYS_BLOCK))? {
+		if path.join("loop").exists() || !read_links(&path.join("slaves"))?.is_empty() {
+			continue;
+		}
+		names.push(name);
+	}
+
+	Ok(names)
+}
+
+/// A whole disk followed by its partitions.
+fn read_disk(name: String, path: &Path) -> Result<Vec<BlockDevice>> {
+	let queue = read_queue(path)?;
+	let mut devices = vec![block_device(name.clone(), None, path, &queue)?];
+
+	// partitions are the subdirectories that contain a `partition` file
+	for (partition, partition_path) in sorted_entries(path)? {
+		if partition_path.join("partition").exists() {
+			devices.push(block_device(
+				partition,
+				Some(name.clone()),
+				&partition_path,
+				&queue,
+			)?);
+		}
+	}
+
+	Ok(devices)
+}
+
+/// Every whole disk from `/sys/block`, each followed by its partitions.
+///
+/// Disks that are removed while they are read, or that lack one of the queue attributes, are
+/// left out.
+///
+/// New function, not in Python psutil.
+pub fn block_devices() -> Result<Vec<BlockDevice>> {
+	let mut devices = Vec::new();
+
+	for (name, path) in sorted_entries(Path::new(SYS_BLOCK))? {
+		match read_disk(name, &path) {
+			Ok(disk) => devices.extend(disk),
+			Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => {}
+			Err(err) => return Err(err),
+		}
+	}
+
+	Ok(devices)
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_scheduler() {
+		assert_eq!(
+			parse_scheduler("none [mq-deadline] kyber bfq\n").as_deref(),
+			Some("mq-deadline")
+		);
+		assert_eq!(parse_scheduler("none\n").as_deref(), Some("none"));
+		assert_eq!(parse_scheduler("none kyber\n"), None);
+	}
+
+	#[test]
+	fn test_parse_lvm_name() {
+		let volume = parse_lvm_name("vg--data-lv--home").unwrap();
+		assert_eq!(volume.volume_group(), "vg-data");
+		assert_eq!(volume.logical_volume(), "lv-home");
+		assert_eq!(volume.layer(), None);
+
+		assert_eq!(parse_lvm_name("vg0-root").unwrap().logical_volume(), "root");
+		assert_eq!(parse_lvm_name("no--separator"), None);
+
+		let pool = parse_lvm_name("vg0-pool-tpool").unwrap();
+		assert_eq!(pool.logical_volume(), "pool");
+		assert_eq!(pool.layer(), Some("tpool"));
+		assert_eq!(parse_lvm_name("vg0-lv-real").unwrap().layer(), Some("real"));
+		assert_eq!(parse_lvm_name("vg0-snap-cow").unwrap().layer(), Some("cow"));
+		assert_eq!(parse_lvm_name("a-b-c-d"), None);
+	}
+
+	#[test]
+	fn test_block_devices() {
+		let devices = block_devices().unwrap();
+
+		for device in devices.iter().filter(|device| device.is_partition()) {
+			let parent = device.parent().unwrap();
+			assert!(devices.iter().any(|disk| disk.name() == parent));
+		}
+		for device in &devices {
+			for slave in device.slaves() {
+				let slave = devices.iter().find(|other| other.name() == slave).unwrap();
+				assert!(slave.holders().iter().any(|holder| holder == device.name()));
+			}
+		}
+	}
+}
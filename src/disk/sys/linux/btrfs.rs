@@ -0,0 +1,243 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::disk::DISK_SECTOR_SIZE;
+use crate::{read_dir, read_file, Bytes, Error, Result};
+
+const SYS_FS_BTRFS: &str = "/sys/fs/btrfs";
+
+/// Allocation of one kind of block group of a btrfs filesystem.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BtrfsSpaceInfo {
+	pub(crate) total: Bytes,
+	pub(crate) used: Bytes,
+	pub(crate) disk_total: Bytes,
+	pub(crate) disk_used: Bytes,
+}
+
+impl BtrfsSpaceInfo {
+	/// Space allocated to block groups of this kind.
+	pub fn total(&self) -> Bytes {
+		self.total
+	}
+
+	/// Space used within the allocated block groups.
+	pub fn used(&self) -> Bytes {
+		self.used
+	}
+
+	/// Space taken on the devices by the allocated block groups, including redundant copies.
+	pub fn disk_total(&self) -> Bytes {
+		self.disk_total
+	}
+
+	/// Space taken on the devices by the used space, including redundant copies.
+	pub fn disk_used(&self) -> Bytes {
+		self.disk_used
+	}
+
+	/// Bytes on the devices per byte stored, e.g. 2 for RAID1.
+	pub fn ratio(&self) -> f64 {
+		match self.total {
+			0 => 1.0,
+			total => self.disk_total as f64 / total as f64,
+		}
+	}
+}
+
+/// A mounted btrfs filesystem from `/sys/fs/btrfs`.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BtrfsFilesystem {
+	pub(crate) uuid: String,
+	pub(crate) label: Option<String>,
+	pub(crate) devices: Vec<String>,
+	pub(crate) device_size: Bytes,
+	pub(crate) data: BtrfsSpaceInfo,
+	pub(crate) metadata: BtrfsSpaceInfo,
+	pub(crate) system: BtrfsSpaceInfo,
+	pub(crate) global_reserve: Bytes,
+}
+
+impl BtrfsFilesystem {
+	pub fn uuid(&self) -> &str {
+		&self.uuid
+	}
+
+	pub fn label(&self) -> Option<&str> {
+		self.label.as_deref()
+	}
+
+	/// Kernel names of the devices, e.g. `sda1`.
+	pub fn devices(&self) -> &[String] {
+		&self.devices
+	}
+
+	/// Combined size of all devices.
+	pub fn device_size(&self) -> Bytes {
+		self.device_size
+	}
+
+	pub fn data(&self) -> &BtrfsSpaceInfo {
+		&self.data
+	}
+
+	pub fn metadata(&self) -> &BtrfsSpaceInfo {
+		&self.metadata
+	}
+
+	pub fn system(&self) -> &BtrfsSpaceInfo {
+		&self.system
+	}
+
+	/// Metadata space kept back for emergencies.
+	pub fn global_reserve(&self) -> Bytes {
+		self.global_reserve
+	}
+
+	/// Device space allocated to block groups.
+	pub fn allocated(&self) -> Bytes {
+		self.data.disk_total + self.metadata.disk_total + self.system.disk_total
+	}
+
+	/// Device space not allocated to any block group yet.
+	pub fn unallocated(&self) -> Bytes {
+		self.device_size.saturating_sub(self.allocated())
+	}
+
+	/// Estimate of the data that still fits, computed like `btrfs filesystem usage`.
+	///
+	/// Unlike `DiskUsage::free`, this accounts for the redundancy of the data profile.
+	pub fn free_estimate(&self) -> Bytes {
+		let data_free = self.data.total.saturating_sub(self.data.used);
+
+		data_free + (self.unallocated() as f64 / self.data.ratio()) as Bytes
+	}
+}
+
+fn read_bytes(path: &Path) -> Result<Bytes> {
+	let contents = read_file(path)?;
+
+	contents.trim().parse().map_err(|err| Error::ParseInt {
+		path: path.into(),
+		contents,
+		source: err,
+	})
+}
+
+fn read_space_info(path: &Path) -> Result<BtrfsSpaceInfo> {
+	Ok(BtrfsSpaceInfo {
+		total: read_bytes(&path.join("total_bytes"))?,
+		used: read_bytes(&path.join("bytes_used"))?,
+		disk_total: read_bytes(&path.join("disk_total"))?,
+		disk_used: read_bytes(&path.join("disk_used"))?,
+	})
+}
+
+fn read_filesystem(uuid: String, path: &Path) -> Result<BtrfsFilesystem> {
+	let allocation = path.join("allocation");
+
+	let label = read_file(path.join("label"))?.trim().to_string();
+
+	let mut devices = Vec::new();
+	let mut device_size = 0;
+	for entry in read_dir(path.join("devices"))? {
+		// links to the block devices in sysfs, whose size is in 512 byte units
+		device_size += read_bytes(&entry.path().join("size"))? * DISK_SECTOR_SIZE;
+		devices.push(entry.file_name().to_string_lossy().into_owned());
+	}
+	devices.sort();
+
+	Ok(BtrfsFilesystem {
+		uuid,
+		label: if label.is_empty() { None } else { Some(label) },
+		devices,
+		device_size,
+		data: read_space_info(&allocation.join("data"))?,
+		metadata: read_space_info(&allocation.join("metadata"))?,
+		system: read_space_info(&allocation.join("system"))?,
+		global_reserve: read_bytes(&allocation.join("global_rsv_size"))?,
+	})
+}
+
+/// Mounted btrfs filesystems, ordered by UUID.
+///
+/// Empty if the btrfs module is not loaded.
+///
+/// New function, not in Python psutil.
+pub fn btrfs_filesystems() -> Result<Vec<BtrfsFilesystem>> {
+	let entries = match read_dir(SYS_FS_BTRFS) {
+		Ok(entries) => entries,
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => {
+			return Ok(Vec::new());
+		}
+		Err(err) => return Err(err),
+	};
+
+	let mut filesystems = entries
+		.iter()
+		// skip `features` and other entries that are not filesystems
+		.filter(|entry| entry.path().join("allocation").exists())
+		.map(|entry| {
+			read_filesystem(
+				entry.file_name().to_string_lossy().into_owned(),
+				&entry.path(),
+			)
+		})
+		.collect::<Result<Vec<_>>>()?;
+	filesystems.sort_by(|a, b| a.uuid.cmp(&b.uuid));
+
+	Ok(filesystems)
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_free_estimate() {
+		const GIB: Bytes = 1024 * 1024 * 1024;
+
+		// two 100 GiB devices with RAID1 data and metadata
+		let filesystem = BtrfsFilesystem {
+			uuid: String::new(),
+			label: None,
+			devices: vec!["sda".into(), "sdb".into()],
+			device_size: 200 * GIB,
+			data: BtrfsSpaceInfo {
+				total: 50 * GIB,
+				used: 40 * GIB,
+				disk_total: 100 * GIB,
+				disk_used: 80 * GIB,
+			},
+			metadata: BtrfsSpaceInfo {
+				total: 2 * GIB,
+				used: GIB,
+				disk_total: 4 * GIB,
+				disk_used: 2 * GIB,
+			},
+			system: BtrfsSpaceInfo::default(),
+			global_reserve: 0,
+		};
+
+		assert_eq!(filesystem.allocated(), 104 * GIB);
+		assert_eq!(filesystem.unallocated(), 96 * GIB);
+		assert_eq!(filesystem.data().ratio(), 2.0);
+		assert_eq!(filesystem.free_estimate(), 58 * GIB);
+	}
+
+	#[test]
+	fn test_btrfs_filesystems() {
+		for filesystem in btrfs_filesystems().unwrap() {
+			assert!(filesystem.allocated() <= filesystem.device_size());
+		}
+	}
+}
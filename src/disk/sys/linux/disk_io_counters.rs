@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use std::time::Duration;
 
-use crate::disk::DiskIoCounters;
+use crate::disk::{disk_names, DiskIoCounters, Gauge, Optional};
 use crate::{read_file, Error, Result};
 
 // Copied from the `psutil` sources:
@@ -19,7 +19,11 @@ use crate::{read_file, Error, Result};
 // * https://github.com/giampaolo/psutil/issues/1305
 // * https://github.com/torvalds/linux/blob/4f671fe2f9523a1ea206f63fe60a7c7b3a56d5c7/include/linux/bio.h#L99
 // * https://lkml.org/lkml/2015/8/17/234
-const DISK_SECTOR_SIZE: u64 = 512;
+//
+// The kernel reports `/proc/diskstats` and `/sys/block/{DISK}/size` in units of `SECTOR_SIZE`
+// whatever the sector size of the device, so this is not a stand-in for the real value, which
+// `BlockDevice::logical_sector_size()` returns.
+pub(crate) const DISK_SECTOR_SIZE: u64 = 512;
 
 const PROC_DISKSTATS: &str = "/proc/diskstats";
 const PROC_PARTITIONS: &str = "/proc/partitions";
@@ -27,8 +31,8 @@ const PROC_PARTITIONS: &str = "/proc/partitions";
 impl FromStr for DiskIoCounters {
 	type Err = Error;
 
-	// At the moment supports format used in Linux 2.6+,
-	// except ignoring discard values introduced in Linux 4.18.
+	// Supports the format used in Linux 2.6+, with the discard fields added in Linux 4.18 and the
+	// flush fields added in Linux 5.5 when present.
 	//
 	// https://www.kernel.org/doc/Documentation/iostats.txt
 	// https://www.kernel.org/doc/Documentation/ABI/testing/procfs-diskstats
@@ -48,6 +52,10 @@ impl FromStr for DiskIoCounters {
 				source: err,
 			})
 		};
+		let parse_optional = |index: usize| -> Result<Optional<u64>> {
+			Ok(Optional(fields.get(index).map(|s| parse(s)).transpose()?))
+		};
+		let optional_millis = |millis: Optional<u64>| Optional(millis.0.map(Duration::from_millis));
 
 		Ok(DiskIoCounters {
 			read_count: parse(fields[3])?,
@@ -59,6 +67,20 @@ impl FromStr for DiskIoCounters {
 			busy_time: Duration::from_millis(parse(fields[12])?),
 			read_merged_count: parse(fields[4])?,
 			write_merged_count: parse(fields[8])?,
+			in_flight: Gauge(parse(fields[11])?),
+			weighted_io_time: Duration::from_millis(parse(fields[13])?),
+			// since Linux 4.18
+			discard_count: parse_optional(14)?,
+			discard_merged_count: parse_optional(15)?,
+			discard_bytes: Optional(
+				parse_optional(16)?
+					.0
+					.map(|sectors| sectors * DISK_SECTOR_SIZE),
+			),
+			discard_time: optional_millis(parse_optional(17)?),
+			// since Linux 5.5
+			flush_count: parse_optional(18)?,
+			flush_time: optional_millis(parse_optional(19)?),
 		})
 	}
 }
@@ -105,3 +127,67 @@ pub(crate) fn disk_io_counters_per_partition() -> Result<HashMap<String, DiskIoC
 
 	Ok(io_counters)
 }
+
+pub(crate) fn disk_io_counters_per_disk(
+	per_partition: HashMap<String, DiskIoCounters>,
+) -> Result<HashMap<String, DiskIoCounters>> {
+	let disks = disk_names()?;
+
+	Ok(per_partition
+		.into_iter()
+		.filter(|(name, _)| disks.contains(name))
+		.collect())
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use crate::disk::os::linux::DiskIoCountersExt;
+
+	#[test]
+	fn test_parse_diskstats() {
+		// Linux 4.17
+		let old =
+			DiskIoCounters::from_str("   8       0 sda 100 2 800 30 50 3 400 20 1 40 60").unwrap();
+		assert_eq!(old.weighted_io_time(), Duration::from_millis(60));
+		assert_eq!(old.discard_count(), None);
+		assert_eq!(old.flush_count(), None);
+
+		// Linux 4.18
+		let old =
+			DiskIoCounters::from_str("   8       0 sda 100 2 800 30 50 3 400 20 1 40 60 7 1 64 5")
+				.unwrap();
+		assert_eq!(old.discard_count(), Some(7));
+		assert_eq!(old.flush_time(), None);
+
+		// Linux 5.5
+		let new = DiskIoCounters::from_str(
+			"   8       0 sda 100 2 800 30 50 3 400 20 1 40 60 7 1 64 5 9 11",
+		)
+		.unwrap();
+		assert_eq!(new.in_flight(), 1);
+		assert_eq!(new.weighted_io_time(), Duration::from_millis(60));
+		assert_eq!(new.discard_count(), Some(7));
+		assert_eq!(new.discard_bytes(), Some(64 * 512));
+		assert_eq!(new.discard_time(), Some(Duration::from_millis(5)));
+		assert_eq!(new.flush_count(), Some(9));
+		assert_eq!(new.flush_time(), Some(Duration::from_millis(11)));
+
+		let sum: DiskIoCounters = vec![new.clone(), new].into_iter().sum();
+		assert_eq!(sum.discard_count(), Some(14));
+		assert_eq!(sum.in_flight(), 2);
+	}
+
+	#[test]
+	fn test_sub_keeps_in_flight() {
+		let prev =
+			DiskIoCounters::from_str("   8       0 sda 100 2 800 30 50 3 400 20 5 40 60").unwrap();
+		let current =
+			DiskIoCounters::from_str("   8       0 sda 150 2 900 40 60 3 480 25 2 50 70").unwrap();
+
+		// the queue got shorter, which must not underflow
+		let delta = current - prev;
+		assert_eq!(delta.read_count(), 50);
+		assert_eq!(delta.in_flight(), 2);
+	}
+}
@@ -0,0 +1,250 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::disk::{DiskIoCounters, DiskIoCountersCollector};
+use crate::{FloatCount, Percent, Result};
+
+/// Per-device statistics between two calls of a [`DiskIoStatsCollector`], computed like
+/// `iostat -x`.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiskIoStats {
+	pub(crate) elapsed: Duration,
+	pub(crate) reads_per_sec: FloatCount,
+	pub(crate) writes_per_sec: FloatCount,
+	pub(crate) read_merged_per_sec: FloatCount,
+	pub(crate) write_merged_per_sec: FloatCount,
+	pub(crate) read_bytes_per_sec: FloatCount,
+	pub(crate) write_bytes_per_sec: FloatCount,
+	pub(crate) read_await: Duration,
+	pub(crate) write_await: Duration,
+	pub(crate) io_await: Duration,
+	pub(crate) avg_queue_size: FloatCount,
+	pub(crate) util_percent: Percent,
+}
+
+impl DiskIoStats {
+	fn new(delta: &DiskIoCounters, elapsed: Duration) -> DiskIoStats {
+		let per_sec = |value: u64| {
+			if elapsed.is_zero() {
+				0.0
+			} else {
+				value as FloatCount / elapsed.as_secs_f64()
+			}
+		};
+		let average = |time: Duration, count: u64| {
+			if count == 0 {
+				Duration::default()
+			} else {
+				Duration::from_secs_f64(time.as_secs_f64() / count as f64)
+			}
+		};
+
+		// discards are included in `await` since sysstat 12.1, flushes are not
+		let discard_count = delta.discard_count.0.unwrap_or_default();
+		let discard_time = delta.discard_time.0.unwrap_or_default();
+
+		let util_percent = if elapsed.is_zero() {
+			0.0
+		} else {
+			// the busy time can exceed the elapsed time by a few ticks
+			(delta.busy_time.as_secs_f64() / elapsed.as_secs_f64() * 100.0).min(100.0) as Percent
+		};
+
+		DiskIoStats {
+			elapsed,
+			reads_per_sec: per_sec(delta.read_count),
+			writes_per_sec: per_sec(delta.write_count),
+			read_merged_per_sec: per_sec(delta.read_merged_count),
+			write_merged_per_sec: per_sec(delta.write_merged_count),
+			read_bytes_per_sec: per_sec(delta.read_bytes),
+			write_bytes_per_sec: per_sec(delta.write_bytes),
+			read_await: average(delta.read_time, delta.read_count),
+			write_await: average(delta.write_time, delta.write_count),
+			io_await: average(
+				delta.read_time + delta.write_time + discard_time,
+				delta.read_count + delta.write_count + discard_count,
+			),
+			avg_queue_size: per_sec(delta.weighted_io_time.as_millis() as u64) / 1000.0,
+			util_percent,
+		}
+	}
+
+	/// Time between the two calls.
+	pub fn elapsed(&self) -> Duration {
+		self.elapsed
+	}
+
+	/// Reads completed per second, `r/s`.
+	pub fn reads_per_sec(&self) -> FloatCount {
+		self.reads_per_sec
+	}
+
+	/// Writes completed per second, `w/s`.
+	pub fn writes_per_sec(&self) -> FloatCount {
+		self.writes_per_sec
+	}
+
+	/// Reads merged per second, `rrqm/s`.
+	pub fn read_merged_per_sec(&self) -> FloatCount {
+		self.read_merged_per_sec
+	}
+
+	/// Writes merged per second, `wrqm/s`.
+	pub fn write_merged_per_sec(&self) -> FloatCount {
+		self.write_merged_per_sec
+	}
+
+	/// Bytes read per second.
+	pub fn read_bytes_per_sec(&self) -> FloatCount {
+		self.read_bytes_per_sec
+	}
+
+	/// Bytes written per second.
+	pub fn write_bytes_per_sec(&self) -> FloatCount {
+		self.write_bytes_per_sec
+	}
+
+	/// Kilobytes read per second, `rkB/s`.
+	pub fn read_kb_per_sec(&self) -> FloatCount {
+		self.read_bytes_per_sec / 1024.0
+	}
+
+	/// Kilobytes written per second, `wkB/s`.
+	pub fn write_kb_per_sec(&self) -> FloatCount {
+		self.write_bytes_per_sec / 1024.0
+	}
+
+	/// Average time for a read to be served, including queueing, `r_await`.
+	pub fn read_await(&self) -> Duration {
+		self.read_await
+	}
+
+	/// Average time for a write to be served, including queueing, `w_await`.
+	pub fn write_await(&self) -> Duration {
+		self.write_await
+	}
+
+	/// Average time for a read, write or discard to be served, including queueing, `await`.
+	pub fn io_await(&self) -> Duration {
+		self.io_await
+	}
+
+	/// Average number of requests in the queue, `aqu-sz`.
+	pub fn avg_queue_size(&self) -> FloatCount {
+		self.avg_queue_size
+	}
+
+	/// Share of the time the device was busy, `%util`.
+	///
+	/// Devices serving requests in parallel, like SSDs and RAID arrays, can be far from saturated
+	/// at 100%.
+	pub fn util_percent(&self) -> Percent {
+		self.util_percent
+	}
+}
+
+/// Get `iostat -x` statistics per device in non-blocking mode.
+///
+/// Devices that appeared since the last call are missing from the result.
+///
+/// Example:
+///
+/// ```no_run
+/// let mut disk_io_stats_collector = psutil::disk::DiskIoStatsCollector::new().unwrap();
+///
+/// std::thread::sleep(std::time::Duration::from_secs(1));
+/// let stats = disk_io_stats_collector.disk_io_stats().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiskIoStatsCollector {
+	disk_io_counters_collector: DiskIoCountersCollector,
+	disk_io_counters: HashMap<String, DiskIoCounters>,
+	instant: Instant,
+}
+
+impl DiskIoStatsCollector {
+	/// Initialize the `DiskIoStatsCollector` so the method calls are ready to be used.
+	pub fn new() -> Result<DiskIoStatsCollector> {
+		let mut disk_io_counters_collector = DiskIoCountersCollector::default();
+		let disk_io_counters = disk_io_counters_collector.disk_io_counters_per_partition()?;
+
+		Ok(DiskIoStatsCollector {
+			disk_io_counters_collector,
+			disk_io_counters,
+			instant: Instant::now(),
+		})
+	}
+
+	/// Returns the statistics since the last time this was called or since
+	/// `DiskIoStatsCollector::new()` was called.
+	pub fn disk_io_stats(&mut self) -> Result<HashMap<String, DiskIoStats>> {
+		let current = self
+			.disk_io_counters_collector
+			.disk_io_counters_per_partition()?;
+		let instant = Instant::now();
+		let elapsed = instant - self.instant;
+
+		let stats = current
+			.iter()
+			.filter_map(|(name, counters)| {
+				let prev = self.disk_io_counters.get(name)?;
+
+				Some((
+					name.clone(),
+					DiskIoStats::new(&counters.saturating_sub(prev), elapsed),
+				))
+			})
+			.collect();
+
+		self.disk_io_counters = current;
+		self.instant = instant;
+
+		Ok(stats)
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use crate::disk::Optional;
+
+	#[test]
+	fn test_disk_io_stats() {
+		let delta = DiskIoCounters {
+			read_count: 100,
+			write_count: 50,
+			read_bytes: 400 * 1024,
+			write_bytes: 200 * 1024,
+			read_time: Duration::from_millis(300),
+			write_time: Duration::from_millis(500),
+			busy_time: Duration::from_millis(1500),
+			read_merged_count: 10,
+			write_merged_count: 4,
+			weighted_io_time: Duration::from_millis(1200),
+			discard_count: Optional(Some(50)),
+			discard_time: Optional(Some(Duration::from_millis(200))),
+			..DiskIoCounters::default()
+		};
+		let stats = DiskIoStats::new(&delta, Duration::from_secs(2));
+
+		assert_eq!(stats.reads_per_sec(), 50.0);
+		assert_eq!(stats.write_merged_per_sec(), 2.0);
+		assert_eq!(stats.read_kb_per_sec(), 200.0);
+		assert_eq!(stats.read_await(), Duration::from_millis(3));
+		assert_eq!(stats.write_await(), Duration::from_millis(10));
+		assert_eq!(stats.io_await(), Duration::from_millis(5));
+		assert_eq!(stats.avg_queue_size(), 0.6);
+		assert_eq!(stats.util_percent(), 75.0);
+
+		let idle = DiskIoStats::new(&DiskIoCounters::default(), Duration::from_secs(2));
+		assert_eq!(idle.read_await(), Duration::default());
+		assert_eq!(idle.util_percent(), 0.0);
+	}
+}
@@ -0,0 +1,409 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use crate::{read_file, Bytes, Count, Error, Percent, Result};
+
+const PROC_MDSTAT: &str = "/proc/mdstat";
+
+/// A member device of an md RAID array.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MdMember {
+	pub(crate) name: String,
+	pub(crate) index: u32,
+	pub(crate) faulty: bool,
+	pub(crate) spare: bool,
+	pub(crate) write_mostly: bool,
+	pub(crate) replacement: bool,
+}
+
+impl MdMember {
+	/// Kernel name of the device, e.g. `sda1`.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Role of the device in the array.
+	pub fn index(&self) -> u32 {
+		self.index
+	}
+
+	/// Failed and no longer used by the array.
+	pub fn faulty(&self) -> bool {
+		self.faulty
+	}
+
+	/// Waiting to replace a failed member.
+	pub fn spare(&self) -> bool {
+		self.spare
+	}
+
+	/// Only read from when no other member can serve the read.
+	pub fn write_mostly(&self) -> bool {
+		self.write_mostly
+	}
+
+	/// Being rebuilt as the replacement of another member.
+	pub fn replacement(&self) -> bool {
+		self.replacement
+	}
+}
+
+/// A resync, recovery, reshape, check or repair of an md RAID array.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MdSync {
+	pub(crate) action: String,
+	pub(crate) percent: Option<Percent>,
+	pub(crate) finish: Option<Duration>,
+	pub(crate) speed: Option<Bytes>,
+}
+
+impl MdSync {
+	/// One of `resync`, `recovery`, `reshape`, `check` or `repair`.
+	pub fn action(&self) -> &str {
+		&self.action
+	}
+
+	/// Progress, `None` while the action is delayed or pending.
+	pub fn percent(&self) -> Option<Percent> {
+		self.percent
+	}
+
+	/// Estimated time until the action is done.
+	pub fn finish(&self) -> Option<Duration> {
+		self.finish
+	}
+
+	/// Bytes synced per second.
+	pub fn speed(&self) -> Option<Bytes> {
+		self.speed
+	}
+}
+
+/// An md software RAID array from `/proc/mdstat`.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MdArray {
+	pub(crate) name: String,
+	pub(crate) active: bool,
+	pub(crate) read_only: bool,
+	pub(crate) level: Option<String>,
+	pub(crate) size: Bytes,
+	pub(crate) members: Vec<MdMember>,
+	pub(crate) disks: Option<Count>,
+	pub(crate) working_disks: Option<Count>,
+	pub(crate) sync: Option<MdSync>,
+}
+
+impl MdArray {
+	/// Kernel name, e.g. `md0`.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Inactive arrays are not fully assembled yet and can't be used.
+	pub fn active(&self) -> bool {
+		self.active
+	}
+
+	pub fn read_only(&self) -> bool {
+		self.read_only
+	}
+
+	/// RAID level, e.g. `raid1` or `linear`. `None` for inactive arrays.
+	pub fn level(&self) -> Option<&str> {
+		self.level.as_deref()
+	}
+
+	pub fn size(&self) -> Bytes {
+		self.size
+	}
+
+	pub fn members(&self) -> &[MdMember] {
+		&self.members
+	}
+
+	/// Number of devices the array is meant to have, `None` for levels without redundancy.
+	pub fn disks(&self) -> Option<Count> {
+		self.disks
+	}
+
+	/// Number of devices that are in sync, `None` for levels without redundancy.
+	pub fn working_disks(&self) -> Option<Count> {
+		self.working_disks
+	}
+
+	/// Whether the array lost redundancy because devices are missing or failed.
+	///
+	/// Inactive arrays count as degraded, since they are usually left over from a failed
+	/// assembly and `/proc/mdstat` doesn't tell how many of their devices are missing.
+	pub fn degraded(&self) -> bool {
+		if !self.active {
+			return true;
+		}
+
+		match (self.disks, self.working_disks) {
+			(Some(disks), Some(working_disks)) => working_disks < disks,
+			_ => false,
+		}
+	}
+
+	/// The action in progress, if any.
+	pub fn sync(&self) -> Option<&MdSync> {
+		self.sync.as_ref()
+	}
+}
+
+/// Parses members like `sdb1[2](W)(F)`.
+fn parse_member(field: &str) -> Option<MdMember> {
+	let (name, rest) = field.split_once('[')?;
+	let (index, flags) = rest.split_once(']')?;
+
+	Some(MdMember {
+		name: name.to_string(),
+		index: index.parse().ok()?,
+		faulty: flags.contains("(F)"),
+		spare: flags.contains("(S)"),
+		write_mostly: flags.contains("(W)"),
+		replacement: flags.contains("(R)"),
+	})
+}
+
+/// Parses lines like
+/// `[==>......]  recovery = 12.6% (1/8) finish=1.5min speed=1024K/sec` or `resync=DELAYED`.
+fn parse_sync(line: &str) -> Option<MdSync> {
+	const ACTIONS: [&str; 5] = ["resync", "recovery", "reshape", "check", "repair"];
+
+	// skip the progress bar
+	let line = match line.strip_prefix('[') {
+		Some(rest) => rest.split_once(']')?.1,
+		None => line,
+	};
+	let (action, rest) = line.split_once('=')?;
+	let action = action.trim();
+	if !ACTIONS.contains(&action) {
+		return None;
+	}
+
+	let mut fields = rest.split_whitespace();
+	let percent = fields
+		.next()
+		.and_then(|percent| percent.strip_suffix('%')?.parse().ok());
+	let mut finish = None;
+	let mut speed = None;
+	for field in fields {
+		if let Some(minutes) = field
+			.strip_prefix("finish=")
+			.and_then(|finish| finish.strip_suffix("min"))
+		{
+			finish = minutes
+				.parse()
+				.ok()
+				.map(|minutes: f64| Duration::from_secs_f64(minutes * 60.0));
+		} else if let Some(kib) = field
+			.strip_prefix("speed=")
+			.and_then(|speed| speed.strip_suffix("K/sec"))
+		{
+			speed = kib.parse().ok().map(|kib: u64| kib * 1024);
+		}
+	}
+
+	Some(MdSync {
+		action: action.to_string(),
+		percent,
+		finish,
+		speed,
+	})
+}
+
+/// Parses `[2/1]` into the number of disks and of working ones.
+fn parse_disks(line: &str) -> Option<(Count, Count)> {
+	line.split_whitespace().find_map(|field| {
+		let (disks, working_disks) = field
+			.strip_prefix('[')?
+			.strip_suffix(']')?
+			.split_once('/')?;
+
+		Some((disks.parse().ok()?, working_disks.parse().ok()?))
+	})
+}
+
+fn parse_mdstat(contents: &str) -> Result<Vec<MdArray>> {
+	let missing_data = |line: &str| Error::MissingData {
+		path: PROC_MDSTAT.into(),
+		contents: line.to_string(),
+	};
+
+	let mut arrays: Vec<MdArray> = Vec::new();
+
+	for line in contents.lines() {
+		if line.starts_with("Personalities") || line.starts_with("unused devices") {
+			continue;
+		}
+
+		// e.g. `md0 : active (auto-read-only) raid1 sdb1[1] sda1[0]`
+		if !line.starts_with(char::is_whitespace) && !line.trim().is_empty() {
+			let (name, rest) = line.split_once(" : ").ok_or_else(|| missing_data(line))?;
+			let mut fields = rest.split_whitespace().peekable();
+
+			let active = match fields.next() {
+				Some("active") => true,
+				Some("inactive") => false,
+				_ => return Err(missing_data(line)),
+			};
+			let mut read_only = false;
+			while let Some(flag) = fields.peek().filter(|field| field.starts_with('(')) {
+				read_only |= flag.contains("read-only");
+				fields.next();
+			}
+			let level = match fields.peek() {
+				Some(field) if !field.contains('[') => fields.next().map(String::from),
+				_ => None,
+			};
+			let members = fields
+				.map(|field| parse_member(field).ok_or_else(|| missing_data(line)))
+				.collect::<Result<_>>()?;
+
+			arrays.push(MdArray {
+				name: name.trim().to_string(),
+				active,
+				read_only,
+				level,
+				size: 0,
+				members,
+				disks: None,
+				working_disks: None,
+				sync: None,
+			});
+			continue;
+		}
+
+		let array = match arrays.last_mut() {
+			Some(array) => array,
+			None => continue,
+		};
+		let trimmed = line.trim();
+
+		// e.g. `1048512 blocks super 1.2 [2/2] [UU]`
+		let mut fields = trimmed.split_whitespace();
+		if let (Some(blocks), Some("blocks")) = (fields.next(), fields.next()) {
+			array.size = blocks.parse::<u64>().map_err(|err| Error::ParseInt {
+				path: PROC_MDSTAT.into(),
+				contents: line.to_string(),
+				source: err,
+			})? * 1024;
+			if let Some((disks, working_disks)) = parse_disks(trimmed) {
+				array.disks = Some(disks);
+				array.working_disks = Some(working_disks);
+			}
+		} else if let Some(sync) = parse_sync(trimmed) {
+			array.sync = Some(sync);
+		}
+	}
+
+	Ok(arrays)
+}
+
+/// md software RAID arrays.
+///
+/// Empty if the md driver is not loaded.
+///
+/// New function, not in Python psutil.
+pub fn md_arrays() -> Result<Vec<MdArray>> {
+	match read_file(PROC_MDSTAT) {
+		Ok(contents) => parse_mdstat(&contents),
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => {
+			Ok(Vec::new())
+		}
+		Err(err) => Err(err),
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_mdstat() {
+		let contents = "\
+Personalities : [raid1] [raid6] [raid5] [raid4]
+md0 : active raid1 sdb1[1] sda1[0]
+      1048512 blocks super 1.2 [2/2] [UU]
+      bitmap: 0/8 pages [0KB], 65536KB chunk
+
+md1 : active raid5 sdd1[3] sdc1[1] sdb2[0](F)
+      2095104 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [_UU]
+      [=====>...............]  recovery = 27.8% (291712/1047552) finish=0.4min speed=26518K/sec
+
+md2 : active (auto-read-only) raid1 sdf1[1] sde1[0]
+      1048512 blocks super 1.2 [2/2] [UU]
+        resync=PENDING
+
+md3 : inactive sdg1[0](S)
+      1048576 blocks super 1.2
+
+md4 : active raid0 sdi1[1] sdh1[0]
+      2095104 blocks super 1.2 512k chunks
+
+unused devices: <none>
+";
+		let arrays = parse_mdstat(contents).unwrap();
+
+		assert_eq!(arrays.len(), 5);
+		assert_eq!(arrays[0].name(), "md0");
+		assert_eq!(arrays[0].level(), Some("raid1"));
+		assert_eq!(arrays[0].size(), 1048512 * 1024);
+		assert_eq!(arrays[0].members()[1].name(), "sda1");
+		assert!(!arrays[0].degraded());
+		assert!(arrays[0].sync().is_none());
+
+		assert!(arrays[1].degraded());
+		assert!(arrays[1].members()[2].faulty());
+		let sync = arrays[1].sync().unwrap();
+		assert_eq!(sync.action(), "recovery");
+		assert_eq!(sync.percent(), Some(27.8));
+		assert_eq!(sync.finish(), Some(Duration::from_secs(24)));
+		assert_eq!(sync.speed(), Some(26518 * 1024));
+
+		assert!(arrays[2].read_only());
+		assert_eq!(arrays[2].sync().unwrap().percent(), None);
+
+		assert!(!arrays[3].active());
+		assert!(arrays[3].degraded());
+		assert_eq!(arrays[3].level(), None);
+		assert!(arrays[3].members()[0].spare());
+
+		assert_eq!(arrays[4].disks(), None);
+		assert!(!arrays[4].degraded());
+	}
+
+	#[test]
+	fn test_parse_mdstat_failed_assembly() {
+		// a raid5 started with one of its three devices missing
+		let contents = "\
+Personalities : [raid6] [raid5] [raid4]
+md127 : inactive sdc1[2](S) sdb1[0](S)
+      2095104 blocks super 1.2
+
+unused devices: <none>
+";
+		let arrays = parse_mdstat(contents).unwrap();
+
+		assert_eq!(arrays.len(), 1);
+		assert!(!arrays[0].active());
+		assert!(arrays[0].degraded());
+		assert_eq!(arrays[0].level(), None);
+		assert_eq!(arrays[0].disks(), None);
+		assert_eq!(arrays[0].members().len(), 2);
+		assert_eq!(arrays[0].size(), 2095104 * 1024);
+	}
+}
@@ -1,6 +1,20 @@
+mod block_devices;
+mod btrfs;
 mod disk_io_counters;
+mod disk_io_stats;
+mod md_arrays;
+mod mount_watcher;
+mod nfs;
 mod partitions;
+mod zfs;
 
+pub use block_devices::*;
+pub use btrfs::*;
 #[allow(unused_imports)]
 pub use disk_io_counters::*;
+pub use disk_io_stats::*;
+pub use md_arrays::*;
+pub use mount_watcher::*;
+pub use nfs::*;
 pub use partitions::*;
+pub use zfs::*;
@@ -0,0 +1,219 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::os::unix::io::AsFd;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use nix::errno::Errno;
+use nix::poll::{poll, PollFd, PollFlags, PollTimeout};
+
+use crate::disk::{parse_mountinfo, Partition, PROC_SELF_MOUNTINFO};
+use crate::{Error, Result};
+
+/// A change of the mount table seen by a [`MountWatcher`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+// events are short-lived, boxing `Changed` would only make matching on it clumsier
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MountEvent {
+	Added(Partition),
+	Removed(Partition),
+	/// The options or propagation of a mount changed, e.g. after `mount -o remount,ro`.
+	Changed {
+		old: Partition,
+		new: Partition,
+	},
+}
+
+/// Key of a mount, its id alone could be reused by an unrelated mount between two reads.
+type MountKey = (u32, String, PathBuf);
+
+fn mount_key(partition: &Partition) -> MountKey {
+	(
+		partition
+			.mount_info
+			.as_ref()
+			.map(|info| info.mount_id)
+			.unwrap_or_default(),
+		partition.device.clone(),
+		partition.mountpoint.clone(),
+	)
+}
+
+fn diff(
+	old: &BTreeMap<MountKey, Partition>,
+	new: &BTreeMap<MountKey, Partition>,
+) -> Vec<MountEvent> {
+	let removed = old
+		.iter()
+		.filter(|(key, _)| !new.contains_key(*key))
+		.map(|(_, partition)| MountEvent::Removed(partition.clone()));
+
+	let added_or_changed = new
+		.iter()
+		.filter_map(|(key, partition)| match old.get(key) {
+			None => Some(MountEvent::Added(partition.clone())),
+			Some(old) if old != partition => Some(MountEvent::Changed {
+				old: old.clone(),
+				new: partition.clone(),
+			}),
+			Some(_) => None,
+		});
+
+	removed.chain(added_or_changed).collect()
+}
+
+/// Watches the mount table of the calling process for mounts being added, removed or changed.
+///
+/// The kernel wakes up `poll(2)` on `/proc/self/mountinfo` with `POLLPRI` whenever the mount
+/// namespace changes, so no time is spent rereading an unchanged table.
+///
+/// New struct, not in Python psutil.
+///
+/// Example:
+///
+/// ```no_run
+/// let mut mount_watcher = psutil::disk::MountWatcher::new().unwrap();
+///
+/// let events = mount_watcher.wait(None).unwrap();
+/// println!("{:?}", events);
+/// ```
+#[derive(Debug)]
+pub struct MountWatcher {
+	file: File,
+	mounts: BTreeMap<MountKey, Partition>,
+}
+
+impl MountWatcher {
+	pub fn new() -> Result<MountWatcher> {
+		let file = File::open(PROC_SELF_MOUNTINFO).map_err(|err| Error::ReadFile {
+			path: PROC_SELF_MOUNTINFO.into(),
+			source: err,
+		})?;
+
+		let mut mount_watcher = MountWatcher {
+			file,
+			mounts: BTreeMap::new(),
+		};
+		mount_watcher.mounts = mount_watcher.read()?;
+
+		Ok(mount_watcher)
+	}
+
+	/// The mounts as of the last call to `new`, `wait` or `poll`.
+	pub fn partitions(&self) -> impl Iterator<Item = &Partition> {
+		self.mounts.values()
+	}
+
+	fn read(&mut self) -> Result<BTreeMap<MountKey, Partition>> {
+		let mut contents = String::new();
+		self.file
+			.seek(SeekFrom::Start(0))
+			.and_then(|_| self.file.read_to_string(&mut contents))
+			.map_err(|err| Error::ReadFile {
+				path: PROC_SELF_MOUNTINFO.into(),
+				source: err,
+			})?;
+
+		Ok(parse_mountinfo(&contents)?
+			.into_iter()
+			.map(|partition| (mount_key(&partition), partition))
+			.collect())
+	}
+
+	/// Rereads the mount table and returns the changes since the last call.
+	fn update(&mut self) -> Result<Vec<MountEvent>> {
+		let mounts = self.read()?;
+		let events = diff(&self.mounts, &mounts);
+		self.mounts = mounts;
+
+		Ok(events)
+	}
+
+	/// Blocks until the mount table changes or `timeout` passes, `None` waits indefinitely.
+	///
+	/// Returns no events on timeout. A mount and unmount in quick succession can cancel out,
+	/// which also returns no events.
+	pub fn wait(&mut self, timeout: Option<Duration>) -> Result<Vec<MountEvent>> {
+		let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+		loop {
+			let timeout = match deadline {
+				Some(deadline) => {
+					PollTimeout::try_from(deadline.saturating_duration_since(Instant::now()))
+						.unwrap_or(PollTimeout::MAX)
+				}
+				None => PollTimeout::NONE,
+			};
+
+			let mut fds = [PollFd::new(self.file.as_fd(), PollFlags::POLLPRI)];
+			match poll(&mut fds, timeout) {
+				Ok(0) => return Ok(Vec::new()),
+				Ok(_) => return self.update(),
+				// interrupted by a signal
+				Err(Errno::EINTR) => continue,
+				Err(err) => return Err(err.into()),
+			}
+		}
+	}
+
+	/// Returns the changes since the last call without blocking.
+	pub fn poll(&mut self) -> Result<Vec<MountEvent>> {
+		self.wait(Some(Duration::ZERO))
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_diff() {
+		let old = parse_mountinfo(
+			"\
+22 1 8:2 / / rw,relatime shared:1 - ext4 /dev/sda2 rw
+35 22 8:3 / /srv rw shared:20 - xfs /dev/sda3 rw
+36 22 8:4 / /media/usb rw - vfat /dev/sdb1 rw
+",
+		)
+		.unwrap();
+		let new = parse_mountinfo(
+			"\
+22 1 8:2 / / rw,relatime shared:1 - ext4 /dev/sda2 rw
+35 22 8:3 / /srv ro shared:20 - xfs /dev/sda3 ro
+36 22 8:5 / /media/usb rw - vfat /dev/sdc1 rw
+",
+		)
+		.unwrap();
+		let index = |partitions: Vec<Partition>| {
+			partitions
+				.into_iter()
+				.map(|partition| (mount_key(&partition), partition))
+				.collect()
+		};
+
+		let events = diff(&index(old.clone()), &index(new.clone()));
+
+		assert_eq!(events.len(), 3);
+		assert!(events.contains(&MountEvent::Removed(old[2].clone())));
+		assert!(events.contains(&MountEvent::Added(new[2].clone())));
+		assert!(events.contains(&MountEvent::Changed {
+			old: old[1].clone(),
+			new: new[1].clone(),
+		}));
+	}
+
+	#[test]
+	fn test_mount_watcher() {
+		let mut mount_watcher = MountWatcher::new().unwrap();
+
+		assert!(mount_watcher.partitions().count() > 0);
+		assert!(mount_watcher.poll().unwrap().is_empty());
+	}
+}
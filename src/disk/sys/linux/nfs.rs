@@ -0,0 +1,702 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::utils::unescape_octal;
+use crate::{read_file, Bytes, Count, Error, FloatCount, Result};
+
+const PROC_SELF_MOUNTSTATS: &str = "/proc/self/mountstats";
+const PROC_NET_RPC_NFS: &str = "/proc/net/rpc/nfs";
+const PROC_NET_RPC_NFSD: &str = "/proc/net/rpc/nfsd";
+
+/// Procedures of NFS version 3 in the order of `/proc/net/rpc/nfs{,d}`.
+const NFS3_PROCEDURES: [&str; 22] = [
+	"null",
+	"getattr",
+	"setattr",
+	"lookup",
+	"access",
+	"readlink",
+	"read",
+	"write",
+	"create",
+	"mkdir",
+	"symlink",
+	"mknod",
+	"remove",
+	"rmdir",
+	"rename",
+	"link",
+	"readdir",
+	"readdirplus",
+	"fsstat",
+	"fsinfo",
+	"pathconf",
+	"commit",
+];
+
+/// Counters of one NFS operation on one mount.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NfsOpStats {
+	pub(crate) ops: Count,
+	pub(crate) transmissions: Count,
+	pub(crate) timeouts: Count,
+	pub(crate) bytes_sent: Bytes,
+	pub(crate) bytes_received: Bytes,
+	pub(crate) queue_time: Duration,
+	pub(crate) rtt: Duration,
+	pub(crate) execute_time: Duration,
+	pub(crate) errors: Option<Count>,
+}
+
+impl NfsOpStats {
+	/// Number of requests.
+	pub fn ops(&self) -> Count {
+		self.ops
+	}
+
+	/// Number of times requests were sent, including retransmissions.
+	pub fn transmissions(&self) -> Count {
+		self.transmissions
+	}
+
+	pub fn retransmissions(&self) -> Count {
+		self.transmissions.saturating_sub(self.ops)
+	}
+
+	/// Number of major timeouts.
+	pub fn timeouts(&self) -> Count {
+		self.timeouts
+	}
+
+	pub fn bytes_sent(&self) -> Bytes {
+		self.bytes_sent
+	}
+
+	pub fn bytes_received(&self) -> Bytes {
+		self.bytes_received
+	}
+
+	/// Time requests waited to be sent, summed over all requests.
+	pub fn queue_time(&self) -> Duration {
+		self.queue_time
+	}
+
+	/// Time between sending requests and receiving the replies, summed over all requests.
+	pub fn rtt(&self) -> Duration {
+		self.rtt
+	}
+
+	/// Time from queueing requests to handling the replies, summed over all requests.
+	pub fn execute_time(&self) -> Duration {
+		self.execute_time
+	}
+
+	/// Number of requests that failed, `None` before Linux 5.3.
+	pub fn errors(&self) -> Option<Count> {
+		self.errors
+	}
+}
+
+/// Bytes transferred by an NFS mount, from the `bytes:` line of `/proc/self/mountstats`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NfsBytes {
+	pub(crate) normal_read: Bytes,
+	pub(crate) normal_write: Bytes,
+	pub(crate) direct_read: Bytes,
+	pub(crate) direct_write: Bytes,
+	pub(crate) server_read: Bytes,
+	pub(crate) server_write: Bytes,
+}
+
+impl NfsBytes {
+	/// Read by applications through the page cache.
+	pub fn normal_read(&self) -> Bytes {
+		self.normal_read
+	}
+
+	/// Written by applications through the page cache.
+	pub fn normal_write(&self) -> Bytes {
+		self.normal_write
+	}
+
+	/// Read by applications with `O_DIRECT`.
+	pub fn direct_read(&self) -> Bytes {
+		self.direct_read
+	}
+
+	/// Written by applications with `O_DIRECT`.
+	pub fn direct_write(&self) -> Bytes {
+		self.direct_write
+	}
+
+	/// Read from the server.
+	pub fn server_read(&self) -> Bytes {
+		self.server_read
+	}
+
+	/// Written to the server.
+	pub fn server_write(&self) -> Bytes {
+		self.server_write
+	}
+}
+
+/// Client statistics of an NFS mount from `/proc/self/mountstats`.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NfsMount {
+	pub(crate) device: String,
+	pub(crate) mountpoint: PathBuf,
+	pub(crate) fstype: String,
+	pub(crate) age: Duration,
+	pub(crate) bytes: NfsBytes,
+	pub(crate) ops: HashMap<String, NfsOpStats>,
+}
+
+impl NfsMount {
+	/// The export, e.g. `server:/export`.
+	pub fn device(&self) -> &str {
+		&self.device
+	}
+
+	pub fn mountpoint(&self) -> &Path {
+		&self.mountpoint
+	}
+
+	/// `nfs` or `nfs4`.
+	pub fn fstype(&self) -> &str {
+		&self.fstype
+	}
+
+	/// Time since the filesystem was mounted.
+	pub fn age(&self) -> Duration {
+		self.age
+	}
+
+	pub fn bytes(&self) -> &NfsBytes {
+		&self.bytes
+	}
+
+	/// Statistics by operation, e.g. `READ` or `GETATTR`.
+	pub fn ops(&self) -> &HashMap<String, NfsOpStats> {
+		&self.ops
+	}
+}
+
+fn parse_u64(value: &str, line: &str, path: &str) -> Result<u64> {
+	value.parse().map_err(|err| Error::ParseInt {
+		path: path.into(),
+		contents: line.to_string(),
+		source: err,
+	})
+}
+
+fn parse_counters(fields: &str, line: &str, path: &str) -> Result<Vec<u64>> {
+	fields
+		.split_whitespace()
+		.map(|value| parse_u64(value, line, path))
+		.collect()
+}
+
+/// Parses per-op lines like `READ: 10 10 0 1640 409600 5 120 130 0`.
+fn parse_op(line: &str) -> Result<Option<(String, NfsOpStats)>> {
+	let (name, values) = match line.trim().split_once(':') {
+		Some(field) => field,
+		None => return Ok(None),
+	};
+	let values = parse_counters(values, line, PROC_SELF_MOUNTSTATS)?;
+	if values.len() < 8 {
+		return Err(Error::MissingData {
+			path: PROC_SELF_MOUNTSTATS.into(),
+			contents: line.to_string(),
+		});
+	}
+
+	Ok(Some((
+		name.to_string(),
+		NfsOpStats {
+			ops: values[0],
+			transmissions: values[1],
+			timeouts: values[2],
+			bytes_sent: values[3],
+			bytes_received: values[4],
+			queue_time: Duration::from_millis(values[5]),
+			rtt: Duration::from_millis(values[6]),
+			execute_time: Duration::from_millis(values[7]),
+			errors: values.get(8).copied(),
+		},
+	)))
+}
+
+fn parse_mountstats(contents: &str) -> Result<Vec<NfsMount>> {
+	let mut mounts: Vec<NfsMount> = Vec::new();
+	// whether the lines below belong to the last NFS mount
+	let mut is_nfs = false;
+	let mut in_ops = false;
+
+	for line in contents.lines() {
+		// e.g. `device server:/export mounted on /mnt/nfs with fstype nfs4 statvers=1.1`
+		if let Some(rest) = line.strip_prefix("device ") {
+			in_ops = false;
+			is_nfs = false;
+
+			let fields: Vec<&str> = rest.split_whitespace().collect();
+			match fields.as_slice() {
+				// not `nfsd`, the control filesystem of the NFS server
+				[device, "mounted", "on", mountpoint, "with", "fstype", fstype, ..]
+					if *fstype == "nfs" || *fstype == "nfs4" =>
+				{
+					mounts.push(NfsMount {
+						device: unescape_octal(device),
+						mountpoint: PathBuf::from(unescape_octal(mountpoint)),
+						fstype: fstype.to_string(),
+						..NfsMount::default()
+					});
+					is_nfs = true;
+				}
+				// other filesystems have no statistics
+				_ => {}
+			}
+			continue;
+		}
+
+		let mount = match mounts.last_mut() {
+			Some(mount) if is_nfs && line.starts_with(char::is_whitespace) => mount,
+			// skip the lines of other filesystems
+			_ => continue,
+		};
+		let trimmed = line.trim();
+
+		if in_ops {
+			if let Some((name, op)) = parse_op(trimmed)? {
+				mount.ops.insert(name, op);
+			}
+		} else if trimmed == "per-op statistics" {
+			in_ops = true;
+		} else if let Some(age) = trimmed.strip_prefix("age:") {
+			mount.age = Duration::from_secs(parse_u64(age.trim(), line, PROC_SELF_MOUNTSTATS)?);
+		} else if let Some(bytes) = trimmed.strip_prefix("bytes:") {
+			let values = parse_counters(bytes, line, PROC_SELF_MOUNTSTATS)?;
+			if values.len() < 6 {
+				return Err(Error::MissingData {
+					path: PROC_SELF_MOUNTSTATS.into(),
+					contents: line.to_string(),
+				});
+			}
+
+			mount.bytes = NfsBytes {
+				normal_read: values[0],
+				normal_write: values[1],
+				direct_read: values[2],
+				direct_write: values[3],
+				server_read: values[4],
+				server_write: values[5],
+			};
+		}
+	}
+
+	Ok(mounts)
+}
+
+/// NFS mounts visible to the calling process with their client statistics.
+///
+/// New function, not in Python psutil.
+pub fn nfs_mounts() -> Result<Vec<NfsMount>> {
+	parse_mountstats(&read_file(PROC_SELF_MOUNTSTATS)?)
+}
+
+/// RPC and NFS procedure counters from `/proc/net/rpc/nfs` or `/proc/net/rpc/nfsd`.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NfsStats {
+	pub(crate) lines: HashMap<String, Vec<u64>>,
+}
+
+impl NfsStats {
+	/// Every line by its label, e.g. `rpc` or `proc3`.
+	pub fn lines(&self) -> &HashMap<String, Vec<u64>> {
+		&self.lines
+	}
+
+	fn get(&self, label: &str, index: usize) -> Count {
+		self.lines
+			.get(label)
+			.and_then(|values| values.get(index))
+			.copied()
+			.unwrap_or_default()
+	}
+
+	/// Number of RPC calls.
+	pub fn rpc_calls(&self) -> Count {
+		self.get("rpc", 0)
+	}
+
+	/// Number of retransmitted RPC calls by a client, or of malformed calls to a server.
+	pub fn rpc_retransmissions_or_bad_calls(&self) -> Count {
+		self.get("rpc", 1)
+	}
+
+	/// Number of calls of each NFS version 3 procedure by name, e.g. `read` or `getattr`.
+	pub fn nfs3_procedures(&self) -> HashMap<&'static str, Count> {
+		// the first value is the number of procedures
+		NFS3_PROCEDURES
+			.iter()
+			.enumerate()
+			.map(|(i, name)| (*name, self.get("proc3", i + 1)))
+			.collect()
+	}
+
+	/// Bytes read from disk by the server, 0 for clients.
+	pub fn io_read_bytes(&self) -> Bytes {
+		self.get("io", 0)
+	}
+
+	/// Bytes written to disk by the server, 0 for clients.
+	pub fn io_write_bytes(&self) -> Bytes {
+		self.get("io", 1)
+	}
+
+	/// Number of server threads, 0 for clients.
+	pub fn threads(&self) -> Count {
+		self.get("th", 0)
+	}
+}
+
+fn parse_nfs_stats(contents: &str, path: &str) -> Result<NfsStats> {
+	let mut lines = HashMap::new();
+
+	for line in contents.lines() {
+		let (label, values) = match line.split_once(' ') {
+			Some(field) => field,
+			None => continue,
+		};
+		// the `th` line has a histogram of floats after the thread count on older kernels
+		let values = values
+			.split_whitespace()
+			.map_while(|value| value.parse().ok())
+			.collect();
+
+		lines.insert(label.to_string(), values);
+	}
+
+	if lines.is_empty() {
+		return Err(Error::MissingData {
+			path: path.into(),
+			contents: contents.to_string(),
+		});
+	}
+
+	Ok(NfsStats { lines })
+}
+
+/// Statistics of the NFS client. Fails if the NFS client module is not loaded.
+///
+/// New function, not in Python psutil.
+pub fn nfs_client_stats() -> Result<NfsStats> {
+	parse_nfs_stats(&read_file(PROC_NET_RPC_NFS)?, PROC_NET_RPC_NFS)
+}
+
+/// Statistics of the NFS server. Fails if the NFS server module is not loaded.
+///
+/// New function, not in Python psutil.
+pub fn nfs_server_stats() -> Result<NfsStats> {
+	parse_nfs_stats(&read_file(PROC_NET_RPC_NFSD)?, PROC_NET_RPC_NFSD)
+}
+
+/// Rates of one NFS operation on one mount between two calls of an [`NfsOpRatesCollector`],
+/// like `nfsiostat`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NfsOpRates {
+	pub(crate) ops_per_sec: FloatCount,
+	pub(crate) retransmissions_per_sec: FloatCount,
+	pub(crate) bytes_per_sec: FloatCount,
+	pub(crate) avg_rtt: Duration,
+	pub(crate) avg_execute_time: Duration,
+}
+
+impl NfsOpRates {
+	fn new(prev: &NfsOpStats, current: &NfsOpStats, elapsed: Duration) -> NfsOpRates {
+		let per_sec = |current: u64, prev: u64| {
+			if elapsed.is_zero() {
+				0.0
+			} else {
+				current.saturating_sub(prev) as FloatCount / elapsed.as_secs_f64()
+			}
+		};
+		let ops = current.ops.saturating_sub(prev.ops);
+		let average = |current: Duration, prev: Duration| {
+			if ops == 0 {
+				Duration::default()
+			} else {
+				let total = current.checked_sub(prev).unwrap_or_default();
+				Duration::from_secs_f64(total.as_secs_f64() / ops as f64)
+			}
+		};
+
+		NfsOpRates {
+			ops_per_sec: per_sec(current.ops, prev.ops),
+			retransmissions_per_sec: per_sec(current.retransmissions(), prev.retransmissions()),
+			bytes_per_sec: per_sec(
+				current.bytes_sent + current.bytes_received,
+				prev.bytes_sent + prev.bytes_received,
+			),
+			avg_rtt: average(current.rtt, prev.rtt),
+			avg_execute_time: average(current.execute_time, prev.execute_time),
+		}
+	}
+
+	pub fn ops_per_sec(&self) -> FloatCount {
+		self.ops_per_sec
+	}
+
+	pub fn retransmissions_per_sec(&self) -> FloatCount {
+		self.retransmissions_per_sec
+	}
+
+	/// Bytes sent and received per second.
+	pub fn bytes_per_sec(&self) -> FloatCount {
+		self.bytes_per_sec
+	}
+
+	/// Average round trip time of the requests.
+	pub fn avg_rtt(&self) -> Duration {
+		self.avg_rtt
+	}
+
+	/// Average time from queueing a request to handling its reply, as seen by applications.
+	pub fn avg_execute_time(&self) -> Duration {
+		self.avg_execute_time
+	}
+}
+
+/// Get per-operation rates of every NFS mount in non-blocking mode.
+///
+/// Mounts that appeared since the last call are missing from the result.
+///
+/// Example:
+///
+/// ```no_run
+/// let mut nfs_collector = psutil::disk::NfsOpRatesCollector::new().unwrap();
+///
+/// std::thread::sleep(std::time::Duration::from_secs(1));
+/// let rates = nfs_collector.nfs_op_rates().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct NfsOpRatesCollector {
+	mounts: HashMap<MountKey, NfsMount>,
+	instant: Instant,
+}
+
+/// Identifies a mount between two calls. Several mounts of the same export can be stacked on one
+/// mount point, so they are told apart by their position among those.
+type MountKey = (String, PathBuf, usize);
+
+fn by_key(mounts: Vec<NfsMount>) -> Vec<(MountKey, NfsMount)> {
+	let mut seen: HashMap<(String, PathBuf), usize> = HashMap::new();
+
+	mounts
+		.into_iter()
+		.map(|mount| {
+			let occurrence = seen
+				.entry((mount.device.clone(), mount.mountpoint.clone()))
+				.or_default();
+			let key = (mount.device.clone(), mount.mountpoint.clone(), *occurrence);
+			*occurrence += 1;
+
+			(key, mount)
+		})
+		.collect()
+}
+
+impl NfsOpRatesCollector {
+	/// Initialize the `NfsOpRatesCollector` so the method calls are ready to be used.
+	pub fn new() -> Result<NfsOpRatesCollector> {
+		Ok(NfsOpRatesCollector {
+			mounts: by_key(nfs_mounts()?).into_iter().collect(),
+			instant: Instant::now(),
+		})
+	}
+
+	/// Returns the rates by mount point and operation since the last time this was called or
+	/// since `NfsOpRatesCollector::new()` was called.
+	///
+	/// Mounts are in the order of `/proc/self/mountstats`, so a mount point that has several
+	/// mounts stacked on it appears once for each of them, the visible one last.
+	pub fn nfs_op_rates(&mut self) -> Result<Vec<(PathBuf, HashMap<String, NfsOpRates>)>> {
+		let current = by_key(nfs_mounts()?);
+		let instant = Instant::now();
+		let elapsed = instant - self.instant;
+
+		let rates = current
+			.iter()
+			.filter_map(|(key, mount)| {
+				let prev = self.mounts.get(key)?;
+				let ops = mount
+					.ops
+					.iter()
+					.filter_map(|(name, op)| {
+						let rates = NfsOpRates::new(prev.ops.get(name)?, op, elapsed);
+						Some((name.clone(), rates))
+					})
+					.collect();
+
+				Some((mount.mountpoint.clone(), ops))
+			})
+			.collect();
+
+		self.mounts = current.into_iter().collect();
+		self.instant = instant;
+
+		Ok(rates)
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_mountstats() {
+		let contents = "\
+device rootfs mounted on / with fstype rootfs
+device proc mounted on /proc with fstype proc
+device server:/export mounted on /mnt/my\\040nfs with fstype nfs4 statvers=1.1
+	opts:	rw,vers=4.2,rsize=1048576,wsize=1048576
+	age:	3600
+	caps:	caps=0x3ffbffff,wtmult=512,dtsize=32768,bsize=0,namlen=255
+	events:	12 345 0 0 7 14 400 0 0 8 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+	bytes:	409600 8192 0 0 409600 8192 100 2
+	RPC iostats version: 1.1  p/v: 100003/4 (nfs)
+	xprt:	tcp 0 0 1 0 15 200 200 0 200 0 2 0 0
+	per-op statistics
+	        NULL: 0 0 0 0 0 0 0 0
+	        READ: 10 12 0 1640 409600 5 120 130 0
+	       WRITE: 2 2 0 8448 272 1 30 31
+device nfsd mounted on /proc/fs/nfsd with fstype nfsd
+device tmpfs mounted on /tmp with fstype tmpfs
+	bytes:	1 2 3 4 5 6 7 8
+	per-op statistics
+	        READ: 99 99 0 0 0 0 0 0 0
+";
+		let mounts = parse_mountstats(contents).unwrap();
+
+		assert_eq!(mounts.len(), 1);
+		let mount = &mounts[0];
+		assert_eq!(mount.device(), "server:/export");
+		assert_eq!(mount.mountpoint(), Path::new("/mnt/my nfs"));
+		assert_eq!(mount.fstype(), "nfs4");
+		assert_eq!(mount.age(), Duration::from_secs(3600));
+		assert_eq!(mount.bytes().server_read(), 409600);
+		// the lines after a non-NFS `device` line don't overwrite those of the NFS mount
+		assert_eq!(mount.bytes().normal_read(), 409600);
+		assert_eq!(mount.ops().len(), 3);
+		assert_eq!(mount.ops()["READ"].ops(), 10);
+
+		let read = &mount.ops()["READ"];
+		assert_eq!(read.retransmissions(), 2);
+		assert_eq!(read.rtt(), Duration::from_millis(120));
+		assert_eq!(read.errors(), Some(0));
+		assert_eq!(mount.ops()["WRITE"].errors(), None);
+	}
+
+	#[test]
+	fn test_nfs_op_rates() {
+		let prev = NfsOpStats::default();
+		let current = NfsOpStats {
+			ops: 20,
+			transmissions: 22,
+			bytes_sent: 1024,
+			bytes_received: 3072,
+			rtt: Duration::from_millis(100),
+			execute_time: Duration::from_millis(200),
+			..NfsOpStats::default()
+		};
+		let rates = NfsOpRates::new(&prev, &current, Duration::from_secs(2));
+
+		assert_eq!(rates.ops_per_sec(), 10.0);
+		assert_eq!(rates.retransmissions_per_sec(), 1.0);
+		assert_eq!(rates.bytes_per_sec(), 2048.0);
+		assert_eq!(rates.avg_rtt(), Duration::from_millis(5));
+		assert_eq!(rates.avg_execute_time(), Duration::from_millis(10));
+
+		// averages below a nanosecond per operation don't truncate to a division by zero
+		let current = NfsOpStats {
+			ops: u64::from(u32::MAX) + 1,
+			rtt: Duration::from_secs(1),
+			..NfsOpStats::default()
+		};
+		let rates = NfsOpRates::new(&prev, &current, Duration::from_secs(1));
+		assert!(rates.avg_rtt() < Duration::from_micros(1));
+	}
+
+	#[test]
+	fn test_by_key() {
+		let mount = |device: &str, mountpoint: &str| NfsMount {
+			device: device.to_string(),
+			mountpoint: PathBuf::from(mountpoint),
+			..NfsMount::default()
+		};
+		let keys: Vec<_> = by_key(vec![
+			mount("server:/a", "/mnt/a"),
+			mount("server:/a", "/mnt/b"),
+			mount("server:/a", "/mnt/a"),
+		])
+		.into_iter()
+		.map(|(key, _)| key)
+		.collect();
+
+		assert_eq!(
+			keys[0],
+			("server:/a".to_string(), PathBuf::from("/mnt/a"), 0)
+		);
+		assert_eq!(
+			keys[1],
+			("server:/a".to_string(), PathBuf::from("/mnt/b"), 0)
+		);
+		assert_eq!(
+			keys[2],
+			("server:/a".to_string(), PathBuf::from("/mnt/a"), 1)
+		);
+	}
+
+	#[test]
+	fn test_parse_nfs_stats() {
+		let contents = "\
+rc 0 12 34
+fh 0 0 0 0 0
+io 4096 8192
+th 8 0 0.000 0.000 0.000 0.000 0.000 0.000 0.000 0.000 0.000 0.000
+net 46 0 46 2
+rpc 46 1 0 0 0
+proc3 22 1 20 0 5 4 0 10 2 0 0 0 0 0 0 0 0 0 0 0 0 0 3
+";
+		let stats = parse_nfs_stats(contents, PROC_NET_RPC_NFSD).unwrap();
+
+		assert_eq!(stats.rpc_calls(), 46);
+		assert_eq!(stats.rpc_retransmissions_or_bad_calls(), 1);
+		assert_eq!(stats.io_write_bytes(), 8192);
+		assert_eq!(stats.threads(), 8);
+
+		let procedures = stats.nfs3_procedures();
+		assert_eq!(procedures["getattr"], 20);
+		assert_eq!(procedures["read"], 10);
+		assert_eq!(procedures["commit"], 3);
+
+		assert!(parse_nfs_stats("", PROC_NET_RPC_NFS).is_err());
+	}
+}
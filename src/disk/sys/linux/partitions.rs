@@ -1,13 +1,122 @@
-use std::path::PathBuf;
-use std::str::FromStr;
-
-use unescape::unescape;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use crate::{read_file, Error, Result};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use crate::disk::{FileSystem, Partition};
+use crate::utils::unescape_octal;
+use crate::{read_file, Error, Result};
 
 const PROC_MOUNTS: &str = "/proc/mounts";
+pub(crate) const PROC_SELF_MOUNTINFO: &str = "/proc/self/mountinfo";
+
+/// How mount and unmount events propagate between this mount and others.
+///
+/// See `mount_namespaces(7)`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Propagation {
+	pub(crate) shared: Option<u32>,
+	pub(crate) master: Option<u32>,
+	pub(crate) propagate_from: Option<u32>,
+	pub(crate) unbindable: bool,
+}
+
+impl Propagation {
+	/// Peer group the mount shares events with, in both directions.
+	pub fn shared(&self) -> Option<u32> {
+		self.shared
+	}
+
+	/// Peer group the mount receives events from.
+	pub fn master(&self) -> Option<u32> {
+		self.master
+	}
+
+	/// Closest dominant peer group in the process's root, if the master is not reachable from it.
+	pub fn propagate_from(&self) -> Option<u32> {
+		self.propagate_from
+	}
+
+	pub fn unbindable(&self) -> bool {
+		self.unbindable
+	}
+
+	/// Neither shared nor a slave.
+	pub fn is_private(&self) -> bool {
+		self.shared.is_none() && self.master.is_none()
+	}
+}
+
+/// Details of a mount that are only in `/proc/self/mountinfo`.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MountInfo {
+	pub(crate) mount_id: u32,
+	pub(crate) parent_id: u32,
+	pub(crate) major: u32,
+	pub(crate) minor: u32,
+	pub(crate) root: PathBuf,
+	pub(crate) propagation: Propagation,
+	pub(crate) per_mount_options: String,
+	pub(crate) super_options: String,
+	pub(crate) bind: bool,
+}
+
+impl MountInfo {
+	/// Unique id of the mount, may be reused after unmounting.
+	pub fn mount_id(&self) -> u32 {
+		self.mount_id
+	}
+
+	/// Id of the mount this one is mounted on, its own id at the top of the tree.
+	pub fn parent_id(&self) -> u32 {
+		self.parent_id
+	}
+
+	/// Major number of the device the filesystem is on, `st_dev` of its files.
+	pub fn major(&self) -> u32 {
+		self.major
+	}
+
+	/// Minor number of the device the filesystem is on, `st_dev` of its files.
+	pub fn minor(&self) -> u32 {
+		self.minor
+	}
+
+	/// Directory within the filesystem that forms the root of the mount.
+	pub fn root(&self) -> &Path {
+		&self.root
+	}
+
+	pub fn propagation(&self) -> &Propagation {
+		&self.propagation
+	}
+
+	/// Options of this mount only, e.g. `nosuid` or `relatime`.
+	pub fn per_mount_options(&self) -> &str {
+		&self.per_mount_options
+	}
+
+	/// Options of the filesystem, shared by every mount of it.
+	pub fn super_options(&self) -> &str {
+		&self.super_options
+	}
+
+	/// Whether the mount shows a filesystem, or a part of it, that was already mounted elsewhere.
+	///
+	/// The first of two identical mounts, e.g. after `mount --bind /a /a`, is not detected.
+	pub fn is_bind_mount(&self) -> bool {
+		self.bind
+	}
+}
 
 impl FromStr for Partition {
 	type Err = Error;
@@ -17,12 +126,13 @@ impl FromStr for Partition {
 		match line.split_whitespace().collect::<Vec<_>>() {
 			fields if fields.len() >= 4 => {
 				Ok(Partition {
-					device: String::from(fields[0]),
+					device: unescape_octal(fields[0]),
 					// need to unescape since some characters are escaped by default like the space character
 					// https://github.com/cjbassi/ytop/issues/29
-					mountpoint: PathBuf::from(unescape(fields[1]).unwrap()), // TODO: can this unwrap fail?
-					filesystem: FileSystem::from_str(fields[2]).unwrap(),    // infallible unwrap
+					mountpoint: PathBuf::from(unescape_octal(fields[1])),
+					filesystem: FileSystem::from_str(fields[2]).unwrap(), // infallible unwrap
 					mount_options: String::from(fields[3]),
+					mount_info: None,
 				})
 			}
 			_ => Err(Error::MissingData {
@@ -33,9 +143,172 @@ impl FromStr for Partition {
 	}
 }
 
-pub fn partitions() -> Result<Vec<Partition>> {
-	read_file(PROC_MOUNTS)?
+fn parse_propagation(fields: &[&str]) -> Propagation {
+	let mut propagation = Propagation::default();
+
+	for field in fields {
+		match field.split_once(':') {
+			Some(("shared", id)) => propagation.shared = id.parse().ok(),
+			Some(("master", id)) => propagation.master = id.parse().ok(),
+			Some(("propagate_from", id)) => propagation.propagate_from = id.parse().ok(),
+			None if *field == "unbindable" => propagation.unbindable = true,
+			// tags added by newer kernels
+			_ => {}
+		}
+	}
+
+	propagation
+}
+
+/// Parses a line of `/proc/[pid]/mountinfo`.
+///
+/// Example: `36 35 98:0 /mnt1 /mnt2 rw,noatime master:1 - ext3 /dev/root rw,errors=continue`
+fn parse_mountinfo_line(line: &str) -> Result<Partition> {
+	let missing_data = || Error::MissingData {
+		path: PROC_SELF_MOUNTINFO.into(),
+		contents: line.to_string(),
+	};
+	let parse = |s: &str| -> Result<u32> {
+		s.parse().map_err(|err| Error::ParseInt {
+			path: PROC_SELF_MOUNTINFO.into(),
+			contents: line.to_string(),
+			source: err,
+		})
+	};
+
+	let fields: Vec<&str> = line.split_whitespace().collect();
+	// the optional fields are terminated by a single hyphen
+	let separator = fields
+		.iter()
+		.skip(6)
+		.position(|field| *field == "-")
+		.map(|position| position + 6)
+		.ok_or_else(missing_data)?;
+	if fields.len() < separator + 3 {
+		return Err(missing_data());
+	}
+
+	let (major, minor) = fields[2].split_once(':').ok_or_else(missing_data)?;
+	let per_mount_options = fields[5].to_string();
+	// the superblock options are missing on some kernels for some filesystems
+	let super_options = fields.get(separator + 3).copied().unwrap_or_default();
+
+	// `/proc/mounts` shows the per-mount options followed by the superblock options, whose
+	// leading `ro` or `rw` is already among the former
+	let mount_options = std::iter::once(per_mount_options.as_str())
+		.chain(
+			super_options
+				.split(',')
+				.filter(|option| !option.is_empty() && *option != "ro" && *option != "rw"),
+		)
+		.collect::<Vec<_>>()
+		.join(",");
+
+	Ok(Partition {
+		device: unescape_octal(fields[separator + 2]),
+		mountpoint: PathBuf::from(unescape_octal(fields[4])),
+		filesystem: FileSystem::from_str(fields[separator + 1]).unwrap(), // infallible unwrap
+		mount_options,
+		mount_info: Some(MountInfo {
+			mount_id: parse(fields[0])?,
+			parent_id: parse(fields[1])?,
+			major: parse(major)?,
+			minor: parse(minor)?,
+			root: PathBuf::from(unescape_octal(fields[3])),
+			propagation: parse_propagation(&fields[6..separator]),
+			per_mount_options,
+			super_options: super_options.to_string(),
+			bind: false,
+		}),
+	})
+}
+
+pub(crate) fn parse_mountinfo(contents: &str) -> Result<Vec<Partition>> {
+	let mut partitions = contents
 		.lines()
-		.map(Partition::from_str)
-		.collect()
+		.map(parse_mountinfo_line)
+		.collect::<Result<Vec<_>>>()?;
+
+	// mounts are listed in the order they were mounted in, so a bind mount comes after a mount of
+	// the same filesystem whose root contains its own
+	let mut roots: HashMap<(u32, u32), Vec<PathBuf>> = HashMap::new();
+	for info in partitions
+		.iter_mut()
+		.filter_map(|partition| partition.mount_info.as_mut())
+	{
+		let earlier = roots.entry((info.major, info.minor)).or_default();
+
+		info.bind = earlier.iter().any(|root| info.root.starts_with(root));
+		earlier.push(info.root.clone());
+	}
+
+	Ok(partitions)
+}
+
+/// Uses `/proc/self/mountinfo`, falling back to `/proc/mounts` before Linux 2.6.26.
+pub fn partitions() -> Result<Vec<Partition>> {
+	match read_file(PROC_SELF_MOUNTINFO) {
+		Ok(contents) => parse_mountinfo(&contents),
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => {
+			read_file(PROC_MOUNTS)?
+				.lines()
+				.map(Partition::from_str)
+				.collect()
+		}
+		Err(err) => Err(err),
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_mounts() {
+		let partition =
+			Partition::from_str("/dev/sdb1 /media/my\\040disk\\ ext4 rw,relatime 0 0").unwrap();
+
+		assert_eq!(partition.mountpoint(), Path::new("/media/my disk\\"));
+		assert!(partition.mount_info.is_none());
+	}
+
+	#[test]
+	fn test_parse_mountinfo() {
+		let contents = "\
+22 1 8:2 / / rw,relatime shared:1 - ext4 /dev/sda2 rw,errors=remount-ro
+35 22 8:3 / /srv rw,nosuid shared:20 master:3 - xfs /dev/sda3 rw,attr2,inode64
+40 22 8:3 /data/www /var/www ro,relatime master:3 - xfs /dev/sda3 rw,attr2,inode64
+41 22 0:45 / /var/lib/docker/overlay2/abc/merged rw,relatime - overlay overlay rw,lowerdir=/l,upperdir=/u
+42 22 0:46 / /mnt/my\\040share rw unbindable propagate_from:7 - tmpfs tmpfs rw,size=1024k
+43 22 8:2 /home /home rw,relatime shared:1 - ext4 /dev/sda2 rw,errors=remount-ro
+";
+		let partitions = parse_mountinfo(contents).unwrap();
+		let info = |i: usize| partitions[i].mount_info.as_ref().unwrap();
+
+		assert_eq!(partitions.len(), 6);
+		assert_eq!(partitions[1].device(), "/dev/sda3");
+		assert_eq!(partitions[1].filesystem(), &FileSystem::Xfs);
+		assert_eq!(partitions[1].mount_options(), "rw,nosuid,attr2,inode64");
+		assert_eq!(info(1).mount_id(), 35);
+		assert_eq!(info(1).parent_id(), 22);
+		assert_eq!((info(1).major(), info(1).minor()), (8, 3));
+		assert_eq!(info(1).propagation().shared(), Some(20));
+		assert_eq!(info(1).propagation().master(), Some(3));
+		assert_eq!(info(1).super_options(), "rw,attr2,inode64");
+		assert!(!info(1).is_bind_mount());
+
+		assert_eq!(info(2).root(), Path::new("/data/www"));
+		assert_eq!(info(2).per_mount_options(), "ro,relatime");
+		assert!(info(2).is_bind_mount());
+		assert!(info(5).is_bind_mount());
+		assert!(!info(3).is_bind_mount());
+
+		assert_eq!(partitions[4].mountpoint(), Path::new("/mnt/my share"));
+		assert!(info(4).propagation().is_private());
+		assert!(info(4).propagation().unbindable());
+		assert_eq!(info(4).propagation().propagate_from(), Some(7));
+
+		assert!(parse_mountinfo("22 1 8:2 / / rw,relatime shared:1 ext4 /dev/sda2\n").is_err());
+		assert!(parse_mountinfo("22 1 8-2 / / rw - ext4 /dev/sda2 rw\n").is_err());
+	}
 }
@@ -0,0 +1,357 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::{read_dir, read_file, Bytes, Count, Error, Result};
+
+const PROC_SPL_KSTAT_ZFS: &str = "/proc/spl/kstat/zfs";
+const KSTAT_DATA_INT32: &str = "1";
+const KSTAT_DATA_INT64: &str = "3";
+const KSTAT_DATA_LONG: &str = "5";
+const KSTAT_DATA_STRING: &str = "7";
+
+/// Splits off the first whitespace separated field, returning it and the trimmed rest.
+fn split_field(line: &str) -> (&str, &str) {
+	let line = line.trim_start();
+	let end = line.find(char::is_whitespace).unwrap_or(line.len());
+
+	(&line[..end], line[end..].trim())
+}
+
+/// Parses a named kstat of the SPL into its numeric and string values.
+///
+/// ```text
+/// 13 1 0x01 123 33456 1234567 12345678
+/// name                            type data
+/// hits                            4    12345
+/// dataset_name                    7    tank/home
+/// ```
+fn parse_kstat(
+	contents: &str,
+	path: &Path,
+) -> Result<(HashMap<String, u64>, HashMap<String, String>)> {
+	let mut numbers = HashMap::new();
+	let mut strings = HashMap::new();
+
+	for line in contents.lines().skip(2) {
+		let (name, rest) = split_field(line);
+		let (data_type, data) = split_field(rest);
+		if name.is_empty() || data_type.is_empty() {
+			continue;
+		}
+
+		let parse_err = |err| Error::ParseInt {
+			path: path.into(),
+			contents: line.to_string(),
+			source: err,
+		};
+		match data_type {
+			// strings, like dataset names, may contain spaces
+			KSTAT_DATA_STRING => {
+				strings.insert(name.to_string(), data.to_string());
+			}
+			// signed values are left out while they are negative
+			KSTAT_DATA_INT32 | KSTAT_DATA_INT64 | KSTAT_DATA_LONG => {
+				let value: i64 = data.parse().map_err(parse_err)?;
+				if let Ok(value) = u64::try_from(value) {
+					numbers.insert(name.to_string(), value);
+				}
+			}
+			_ => {
+				numbers.insert(name.to_string(), data.parse().map_err(parse_err)?);
+			}
+		}
+	}
+
+	Ok((numbers, strings))
+}
+
+/// Statistics of the ZFS adaptive replacement cache from `/proc/spl/kstat/zfs/arcstats`.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ArcStats {
+	pub(crate) stats: HashMap<String, u64>,
+}
+
+impl ArcStats {
+	/// Every statistic by name. Signed ones, like `memory_available_bytes`, are missing while
+	/// they are negative.
+	pub fn stats(&self) -> &HashMap<String, u64> {
+		&self.stats
+	}
+
+	pub fn get(&self, name: &str) -> Option<u64> {
+		self.stats.get(name).copied()
+	}
+
+	fn get_or_default(&self, name: &str) -> u64 {
+		self.get(name).unwrap_or_default()
+	}
+
+	/// Current size of the cache.
+	pub fn size(&self) -> Bytes {
+		self.get_or_default("size")
+	}
+
+	/// Size the cache is aiming for.
+	pub fn target_size(&self) -> Bytes {
+		self.get_or_default("c")
+	}
+
+	pub fn min_size(&self) -> Bytes {
+		self.get_or_default("c_min")
+	}
+
+	pub fn max_size(&self) -> Bytes {
+		self.get_or_default("c_max")
+	}
+
+	pub fn hits(&self) -> Count {
+		self.get_or_default("hits")
+	}
+
+	pub fn misses(&self) -> Count {
+		self.get_or_default("misses")
+	}
+
+	/// Hits of the level 2 cache on a separate device, if any.
+	pub fn l2_hits(&self) -> Count {
+		self.get_or_default("l2_hits")
+	}
+
+	pub fn l2_misses(&self) -> Count {
+		self.get_or_default("l2_misses")
+	}
+
+	/// Share of the lookups served from the cache since the module was loaded, between 0 and 1.
+	pub fn hit_ratio(&self) -> f64 {
+		hit_ratio(self.hits(), self.misses())
+	}
+
+	/// Share of the lookups served from the cache since `earlier` was read, between 0 and 1.
+	pub fn hit_ratio_since(&self, earlier: &ArcStats) -> f64 {
+		hit_ratio(
+			self.hits().saturating_sub(earlier.hits()),
+			self.misses().saturating_sub(earlier.misses()),
+		)
+	}
+}
+
+fn hit_ratio(hits: Count, misses: Count) -> f64 {
+	match hits + misses {
+		0 => 0.0,
+		total => hits as f64 / total as f64,
+	}
+}
+
+/// Fails if the ZFS module is not loaded.
+///
+/// New function, not in Python psutil.
+pub fn arc_stats() -> Result<ArcStats> {
+	let path = Path::new(PROC_SPL_KSTAT_ZFS).join("arcstats");
+	let (stats, _) = parse_kstat(&read_file(&path)?, &path)?;
+
+	Ok(ArcStats { stats })
+}
+
+/// I/O counters of a ZFS dataset, from its `objset-*` kstat.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ZfsDataset {
+	pub(crate) name: String,
+	pub(crate) read_count: Count,
+	pub(crate) write_count: Count,
+	pub(crate) read_bytes: Bytes,
+	pub(crate) write_bytes: Bytes,
+}
+
+impl ZfsDataset {
+	/// Full name, e.g. `tank/home`.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn read_count(&self) -> Count {
+		self.read_count
+	}
+
+	pub fn write_count(&self) -> Count {
+		self.write_count
+	}
+
+	pub fn read_bytes(&self) -> Bytes {
+		self.read_bytes
+	}
+
+	pub fn write_bytes(&self) -> Bytes {
+		self.write_bytes
+	}
+}
+
+/// An imported ZFS pool from `/proc/spl/kstat/zfs/<pool>`.
+///
+/// The kstats don't include the capacity of a pool. [`disk_usage`](crate::disk::disk_usage) on
+/// a mounted dataset reports its used and available space, which accounts for compression,
+/// quotas and redundancy.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZfsPool {
+	pub(crate) name: String,
+	pub(crate) state: Option<String>,
+	pub(crate) datasets: Vec<ZfsDataset>,
+}
+
+impl ZfsPool {
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Health of the pool, e.g. `ONLINE` or `DEGRADED`. `None` before ZFS 0.8.
+	pub fn state(&self) -> Option<&str> {
+		self.state.as_deref()
+	}
+
+	/// Datasets in use, ordered by name.
+	pub fn datasets(&self) -> &[ZfsDataset] {
+		&self.datasets
+	}
+
+	/// Whether the pool is in any state other than `ONLINE`.
+	pub fn degraded(&self) -> bool {
+		matches!(self.state(), Some(state) if state != "ONLINE")
+	}
+}
+
+fn read_dataset(path: &Path) -> Result<ZfsDataset> {
+	let contents = read_file(path)?;
+	let (numbers, strings) = parse_kstat(&contents, path)?;
+	let get = |name: &str| numbers.get(name).copied().unwrap_or_default();
+
+	Ok(ZfsDataset {
+		name: strings
+			.get("dataset_name")
+			.cloned()
+			.ok_or_else(|| Error::MissingData {
+				path: path.into(),
+				contents: contents.clone(),
+			})?,
+		read_count: get("reads"),
+		write_count: get("writes"),
+		read_bytes: get("nread"),
+		write_bytes: get("nwritten"),
+	})
+}
+
+fn read_pool(name: String, path: &Path) -> Result<ZfsPool> {
+	let state = match read_file(path.join("state")) {
+		Ok(state) => Some(state.trim().to_string()),
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => None,
+		Err(err) => return Err(err),
+	};
+
+	let mut datasets = read_dir(path)?
+		.iter()
+		.filter(|entry| entry.file_name().to_string_lossy().starts_with("objset-"))
+		.map(|entry| read_dataset(&entry.path()))
+		.collect::<Result<Vec<_>>>()?;
+	datasets.sort_by(|a, b| a.name.cmp(&b.name));
+
+	Ok(ZfsPool {
+		name,
+		state,
+		datasets,
+	})
+}
+
+/// Imported ZFS pools, ordered by name.
+///
+/// Empty if the ZFS module is not loaded.
+///
+/// New function, not in Python psutil.
+pub fn zfs_pools() -> Result<Vec<ZfsPool>> {
+	let entries = match read_dir(PROC_SPL_KSTAT_ZFS) {
+		Ok(entries) => entries,
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => {
+			return Ok(Vec::new());
+		}
+		Err(err) => return Err(err),
+	};
+
+	let mut pools = entries
+		.iter()
+		// the module wide kstats like `arcstats` are files, pools are directories
+		.filter(|entry| entry.path().is_dir())
+		.map(|entry| {
+			read_pool(
+				entry.file_name().to_string_lossy().into_owned(),
+				&entry.path(),
+			)
+		})
+		.collect::<Result<Vec<_>>>()?;
+	pools.sort_by(|a, b| a.name.cmp(&b.name));
+
+	Ok(pools)
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_kstat() {
+		let contents = "\
+13 1 0x01 147 39984 5011836424 2290536624816
+name                            type data
+hits                            4    900
+misses                          4    100
+c                               4    2147483648
+size                            4    1073741824
+memory_available_bytes          3    -524288
+arc_need_free                   1    -1
+arc_loaned_bytes                5    -4096
+arc_meta_min                    3    16777216
+";
+		let (stats, _) = parse_kstat(contents, Path::new("arcstats")).unwrap();
+		let arc_stats = ArcStats { stats };
+
+		assert_eq!(arc_stats.size(), 1024 * 1024 * 1024);
+		assert_eq!(arc_stats.target_size(), 2 * 1024 * 1024 * 1024);
+		assert_eq!(arc_stats.hit_ratio(), 0.9);
+		assert_eq!(arc_stats.hit_ratio_since(&arc_stats), 0.0);
+		assert_eq!(arc_stats.get("memory_available_bytes"), None);
+		assert_eq!(arc_stats.get("arc_need_free"), None);
+		assert_eq!(arc_stats.get("arc_loaned_bytes"), None);
+		assert_eq!(arc_stats.get("arc_meta_min"), Some(16 * 1024 * 1024));
+
+		let contents = "\
+31 1 0x01 7 2160 5214539133 93766950283
+name                            type data
+dataset_name                    7    tank/home videos
+writes                          4    10
+nwritten                        4    40960
+reads                           4    20
+nread                           4    81920
+";
+		let (numbers, strings) = parse_kstat(contents, Path::new("objset-0x36")).unwrap();
+		assert_eq!(strings["dataset_name"], "tank/home videos");
+		assert_eq!(numbers["nread"], 81920);
+	}
+
+	#[test]
+	fn test_zfs_pools() {
+		for pool in zfs_pools().unwrap() {
+			assert!(!pool.name().is_empty());
+		}
+	}
+}
@@ -1,15 +1,29 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use std::path::Path;
 
 use nix::sys;
+use nix::sys::statvfs::FsFlags;
 
-use crate::{Bytes, Percent, Result};
+use crate::disk::{partitions_physical, Partition};
+use crate::utils::u64_percent;
+use crate::{Bytes, Count, Percent, Result};
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
 #[derive(Clone, Debug, Default)]
 pub struct DiskUsage {
 	pub(crate) total: Bytes,
 	pub(crate) used: Bytes,
 	pub(crate) free: Bytes,
 	pub(crate) percent: Percent,
+	pub(crate) reserved: Bytes,
+	pub(crate) block_size: Bytes,
+	pub(crate) inodes_total: Count,
+	pub(crate) inodes_used: Count,
+	pub(crate) inodes_free: Count,
+	pub(crate) read_only: bool,
 }
 
 impl DiskUsage {
@@ -32,6 +46,55 @@ impl DiskUsage {
 	pub fn percent(&self) -> Percent {
 		self.percent
 	}
+
+	/// Bytes only available to root, which count as neither used nor free.
+	///
+	/// New method, not in Python psutil.
+	pub fn reserved(&self) -> Bytes {
+		self.reserved
+	}
+
+	/// Preferred block size for I/O on the filesystem.
+	///
+	/// New method, not in Python psutil.
+	pub fn block_size(&self) -> Bytes {
+		self.block_size
+	}
+
+	/// Total number of inodes, 0 for filesystems that allocate them dynamically like btrfs.
+	///
+	/// New method, not in Python psutil.
+	pub fn inodes_total(&self) -> Count {
+		self.inodes_total
+	}
+
+	/// New method, not in Python psutil.
+	pub fn inodes_used(&self) -> Count {
+		self.inodes_used
+	}
+
+	/// New method, not in Python psutil.
+	pub fn inodes_free(&self) -> Count {
+		self.inodes_free
+	}
+
+	/// Percentage of the inodes used (inodes_used / inodes_total * 100), 0 if there are none.
+	///
+	/// New method, not in Python psutil.
+	pub fn inodes_percent(&self) -> Percent {
+		if self.inodes_total == 0 {
+			return 0.0;
+		}
+
+		u64_percent(self.inodes_used, self.inodes_total)
+	}
+
+	/// Whether the filesystem is mounted read-only.
+	///
+	/// New method, not in Python psutil.
+	pub fn read_only(&self) -> bool {
+		self.read_only
+	}
 }
 
 /// This returns information about the filesystem containing the path.
@@ -58,10 +121,51 @@ where
 	let total_user = used + free;
 	let percent = ((used as f64 / total_user as f64) * 100.0) as f32;
 
+	let inodes_total = statvfs.files() as u64;
+	let inodes_free = statvfs.files_free() as u64;
+
 	Ok(DiskUsage {
 		total,
 		used,
 		free,
 		percent,
+		reserved: avail_to_root.saturating_sub(free),
+		block_size: statvfs.block_size() as u64,
+		inodes_total,
+		inodes_used: inodes_total.saturating_sub(inodes_free),
+		inodes_free,
+		read_only: statvfs.flags().contains(FsFlags::ST_RDONLY),
 	})
 }
+
+/// Calls [`disk_usage`] for the mount point of every physical partition.
+///
+/// A partition that can't be queried, e.g. because of missing permissions, gets an error without
+/// affecting the others. Network filesystems aren't physical and are left out, but there is no
+/// timeout for the rest: `statvfs` blocks for as long as e.g. the daemon behind a `fuseblk`
+/// mount or a failing disk doesn't respond, and so does this function.
+///
+/// New function, not in Python psutil.
+pub fn disk_usage_all() -> Result<Vec<(Partition, Result<DiskUsage>)>> {
+	Ok(partitions_physical()?
+		.into_iter()
+		.map(|partition| {
+			let usage = disk_usage(partition.mountpoint());
+			(partition, usage)
+		})
+		.collect())
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_disk_usage() {
+		let usage = disk_usage("/").unwrap();
+
+		assert!(usage.inodes_used() <= usage.inodes_total());
+		assert!(usage.used() + usage.free() + usage.reserved() <= usage.total());
+		assert!(disk_usage_all().is_ok());
+	}
+}
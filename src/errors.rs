@@ -120,7 +120,7 @@ pub(crate) fn glob(path: &str) -> Vec<Result<PathBuf>> {
 		.map(|result| {
 			result.map_err(|err| Error::ReadFile {
 				path: path.into(),
-				source: err.into_error(),
+				source: err.into(),
 			})
 		})
 		.collect()
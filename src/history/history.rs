@@ -0,0 +1,111 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Instant;
+
+use crate::history::Series;
+
+/// A [`Series`] per key, e.g. per interface name or per pid.
+///
+/// Every series holds at most `capacity` samples. Keys are never dropped automatically; call
+/// [`History::retain`] for keys that can disappear, such as pids of exited processes.
+///
+/// ```
+/// use psutil::history::History;
+///
+/// let mut history = History::new(300);
+/// history.push("eth0", 12.5);
+/// history.push("eth0", 14.0);
+/// assert_eq!(history.get("eth0").unwrap().all().max(), Some(14.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct History<K> {
+	capacity: usize,
+	series: HashMap<K, Series>,
+}
+
+impl<K> History<K>
+where
+	K: Eq + Hash,
+{
+	/// # Panics
+	///
+	/// Panics if `capacity` is zero.
+	pub fn new(capacity: usize) -> History<K> {
+		assert!(capacity > 0, "the capacity must not be zero");
+
+		History {
+			capacity,
+			series: HashMap::new(),
+		}
+	}
+
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	/// Records `value` for `key` as measured now.
+	pub fn push(&mut self, key: K, value: f64) {
+		self.push_at(key, Instant::now(), value);
+	}
+
+	/// Records `value` for `key` as measured at `instant`.
+	pub fn push_at(&mut self, key: K, instant: Instant, value: f64) {
+		let capacity = self.capacity;
+		self.series
+			.entry(key)
+			.or_insert_with(|| Series::new(capacity))
+			.push_at(instant, value);
+	}
+
+	/// Records every value of `values` at the same instant.
+	pub fn extend<I>(&mut self, values: I)
+	where
+		I: IntoIterator<Item = (K, f64)>,
+	{
+		let instant = Instant::now();
+		for (key, value) in values {
+			self.push_at(key, instant, value);
+		}
+	}
+
+	pub fn get<Q>(&self, key: &Q) -> Option<&Series>
+	where
+		K: Borrow<Q>,
+		Q: Eq + Hash + ?Sized,
+	{
+		self.series.get(key)
+	}
+
+	pub fn remove<Q>(&mut self, key: &Q) -> Option<Series>
+	where
+		K: Borrow<Q>,
+		Q: Eq + Hash + ?Sized,
+	{
+		self.series.remove(key)
+	}
+
+	/// Keeps only the series whose key matches `predicate`.
+	pub fn retain<P>(&mut self, mut predicate: P)
+	where
+		P: FnMut(&K) -> bool,
+	{
+		self.series.retain(|key, _| predicate(key));
+	}
+
+	pub fn keys(&self) -> impl Iterator<Item = &K> {
+		self.series.keys()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (&K, &Series)> {
+		self.series.iter()
+	}
+
+	pub fn len(&self) -> usize {
+		self.series.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.series.is_empty()
+	}
+}
@@ -0,0 +1,95 @@
+//! Bounded history of metric values with rolling statistics.
+//!
+//! The collectors only remember the previous value. Pushing the values they return into a
+//! [`History`] keeps the last N samples per key, for sparklines or checks such as a value being
+//! sustained above a threshold for some time.
+
+#[allow(clippy::module_inception)]
+mod history;
+mod series;
+mod window;
+
+pub use history::*;
+pub use series::*;
+pub use window::*;
+
+#[cfg(test)]
+mod unit_tests {
+	use std::time::{Duration, Instant};
+
+	use super::*;
+
+	fn series(values: &[f64]) -> (Instant, Series) {
+		let start = Instant::now();
+		let mut series = Series::new(4);
+		for (i, &value) in values.iter().enumerate() {
+			series.push_at(start + Duration::from_secs(i as u64), value);
+		}
+
+		(start, series)
+	}
+
+	#[test]
+	fn test_series_bounded() {
+		let (_, series) = series(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+		assert_eq!(series.len(), 4);
+		assert_eq!(series.values().collect::<Vec<_>>(), [3.0, 4.0, 5.0, 6.0]);
+		assert_eq!(series.last(), Some(6.0));
+	}
+
+	#[test]
+	fn test_window_stats() {
+		let (_, series) = series(&[4.0, 1.0, 3.0, 2.0]);
+		let all = series.all();
+
+		assert_eq!(all.min(), Some(1.0));
+		assert_eq!(all.max(), Some(4.0));
+		assert_eq!(all.mean(), Some(2.5));
+		assert_eq!(all.percentile(0.0), Some(1.0));
+		assert_eq!(all.percentile(50.0), Some(2.5));
+		assert_eq!(all.percentile(100.0), Some(4.0));
+		assert_eq!(all.duration(), Duration::from_secs(3));
+
+		let recent = series.recent(Duration::from_secs(1));
+		assert_eq!(recent.values().collect::<Vec<_>>(), [3.0, 2.0]);
+
+		assert_eq!(Series::new(1).all().mean(), None);
+	}
+
+	#[test]
+	fn test_ewma() {
+		let (_, constant) = series(&[5.0, 5.0, 5.0]);
+		assert_eq!(constant.ewma(Duration::from_secs(60)), Some(5.0));
+
+		let (_, step) = series(&[0.0, 10.0]);
+		let expected = 10.0 * (1.0 - (-1.0f64).exp());
+		assert!((step.ewma(Duration::from_secs(1)).unwrap() - expected).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_sustained() {
+		let (_, series) = series(&[50.0, 95.0, 96.0, 97.0]);
+
+		assert!(series.sustained(Duration::from_secs(2), |value| value > 90.0));
+		assert!(!series.sustained(Duration::from_secs(3), |value| value > 90.0));
+		// the history only reaches back 3 seconds
+		assert!(!series.sustained(Duration::from_secs(10), |_| true));
+	}
+
+	#[test]
+	fn test_history() {
+		let mut history = History::new(2);
+		history.extend(vec![(1, 10.0), (2, 20.0)]);
+		history.push(1, 30.0);
+		history.push(1, 40.0);
+
+		assert_eq!(
+			history.get(&1).unwrap().values().collect::<Vec<_>>(),
+			[30.0, 40.0]
+		);
+		history.retain(|&pid| pid != 2);
+		assert!(history.get(&2).is_none());
+		assert_eq!(history.len(), 1);
+	}
+}
@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::history::Window;
+
+/// The last `capacity` values of a single metric, together with when they were recorded.
+///
+/// Once full, pushing a value drops the oldest one, so memory use is bounded.
+#[derive(Clone, Debug)]
+pub struct Series {
+	capacity: usize,
+	samples: VecDeque<(Instant, f64)>,
+}
+
+impl Series {
+	/// # Panics
+	///
+	/// Panics if `capacity` is zero.
+	pub fn new(capacity: usize) -> Series {
+		assert!(capacity > 0, "the capacity must not be zero");
+
+		Series {
+			capacity,
+			samples: VecDeque::with_capacity(capacity),
+		}
+	}
+
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	pub fn len(&self) -> usize {
+		self.samples.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.samples.is_empty()
+	}
+
+	pub fn clear(&mut self) {
+		self.samples.clear();
+	}
+
+	/// Records `value` as measured now.
+	pub fn push(&mut self, value: f64) {
+		self.push_at(Instant::now(), value);
+	}
+
+	/// Records `value` as measured at `instant`, which should not be before the previous one.
+	pub fn push_at(&mut self, instant: Instant, value: f64) {
+		if self.samples.len() == self.capacity {
+			self.samples.pop_front();
+		}
+		self.samples.push_back((instant, value));
+	}
+
+	/// The most recent value.
+	pub fn last(&self) -> Option<f64> {
+		self.samples.back().map(|&(_, value)| value)
+	}
+
+	/// All values from oldest to newest, e.g. to draw a sparkline.
+	pub fn values(&self) -> impl DoubleEndedIterator<Item = f64> + '_ {
+		self.samples.iter().map(|&(_, value)| value)
+	}
+
+	/// All samples from oldest to newest.
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Instant, f64)> + '_ {
+		self.samples.iter().copied()
+	}
+
+	/// Every recorded sample.
+	pub fn all(&self) -> Window<'_> {
+		Window::new(self.samples.range(..))
+	}
+
+	/// The samples recorded at most `duration` before the most recent one.
+	pub fn recent(&self, duration: Duration) -> Window<'_> {
+		let newest = match self.samples.back() {
+			Some(&(instant, _)) => instant,
+			None => return self.all(),
+		};
+		let start = self
+			.samples
+			.partition_point(|&(instant, _)| newest.duration_since(instant) > duration);
+
+		Window::new(self.samples.range(start..))
+	}
+
+	/// Whether every value of the last `duration` matches `predicate`, e.g. cpu usage
+	/// sustained above 90% for 5 minutes.
+	///
+	/// Returns `false` if the history does not reach back `duration` yet, as the values before
+	/// the oldest sample are unknown.
+	pub fn sustained<P>(&self, duration: Duration, predicate: P) -> bool
+	where
+		P: FnMut(f64) -> bool,
+	{
+		match (self.samples.front(), self.samples.back()) {
+			(Some(&(oldest, _)), Some(&(newest, _)))
+				if newest.duration_since(oldest) >= duration =>
+			{
+				self.recent(duration).values().all(predicate)
+			}
+			_ => false,
+		}
+	}
+
+	/// Exponentially weighted moving average over every sample, see [`Window::ewma`].
+	pub fn ewma(&self, period: Duration) -> Option<f64> {
+		self.all().ewma(period)
+	}
+}
@@ -0,0 +1,111 @@
+use std::collections::vec_deque;
+use std::time::{Duration, Instant};
+
+/// A contiguous range of the samples of a [`Series`](crate::history::Series).
+///
+/// All statistics return `None` if the window is empty.
+#[derive(Clone, Debug)]
+pub struct Window<'a> {
+	samples: vec_deque::Iter<'a, (Instant, f64)>,
+}
+
+impl<'a> Window<'a> {
+	pub(crate) fn new(samples: vec_deque::Iter<'a, (Instant, f64)>) -> Window<'a> {
+		Window { samples }
+	}
+
+	pub fn len(&self) -> usize {
+		self.samples.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.samples.len() == 0
+	}
+
+	/// Values from oldest to newest.
+	pub fn values(&self) -> impl DoubleEndedIterator<Item = f64> + 'a {
+		self.samples.clone().map(|&(_, value)| value)
+	}
+
+	/// Samples from oldest to newest.
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Instant, f64)> + 'a {
+		self.samples.clone().copied()
+	}
+
+	/// Time between the oldest and the newest sample.
+	pub fn duration(&self) -> Duration {
+		match (
+			self.samples.clone().next(),
+			self.samples.clone().next_back(),
+		) {
+			(Some(&(oldest, _)), Some(&(newest, _))) => newest.duration_since(oldest),
+			_ => Duration::default(),
+		}
+	}
+
+	pub fn min(&self) -> Option<f64> {
+		self.values().reduce(f64::min)
+	}
+
+	pub fn max(&self) -> Option<f64> {
+		self.values().reduce(f64::max)
+	}
+
+	pub fn sum(&self) -> f64 {
+		self.values().sum()
+	}
+
+	pub fn mean(&self) -> Option<f64> {
+		if self.is_empty() {
+			return None;
+		}
+
+		Some(self.sum() / self.len() as f64)
+	}
+
+	/// The `percentile`th percentile, interpolating linearly between the closest values.
+	///
+	/// # Panics
+	///
+	/// Panics if `percentile` is not between 0 and 100.
+	pub fn percentile(&self, percentile: f64) -> Option<f64> {
+		assert!(
+			(0.0..=100.0).contains(&percentile),
+			"the percentile must be between 0 and 100"
+		);
+
+		let mut values: Vec<f64> = self.values().collect();
+		if values.is_empty() {
+			return None;
+		}
+		values.sort_by(f64::total_cmp);
+
+		let rank = percentile / 100.0 * (values.len() - 1) as f64;
+		let lower = values[rank.floor() as usize];
+		let upper = values[rank.ceil() as usize];
+
+		Some(lower + (upper - lower) * rank.fract())
+	}
+
+	/// Exponentially weighted moving average, decaying like the load averages.
+	///
+	/// Each sample is weighted by the time since the previous one, so a value's influence falls
+	/// to about 37% after `period`. Irregular sampling intervals are therefore handled correctly.
+	pub fn ewma(&self, period: Duration) -> Option<f64> {
+		let mut samples = self.samples.clone();
+		let &(mut prev, mut average) = samples.next()?;
+
+		for &(instant, value) in samples {
+			let elapsed = instant.duration_since(prev).as_secs_f64();
+			let alpha = if period.is_zero() {
+				1.0
+			} else {
+				1.0 - (-elapsed / period.as_secs_f64()).exp()
+			};
+			average += alpha * (value - average);
+			prev = instant;
+		}
+
+		Some(average)
+	}
+}
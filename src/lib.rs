@@ -41,24 +41,45 @@ pub mod cpu;
 #[cfg(feature = "disk")]
 pub mod disk;
 
+#[cfg(feature = "history")]
+pub mod history;
+
 #[cfg(feature = "host")]
 pub mod host;
 
 #[cfg(feature = "memory")]
 pub mod memory;
 
+#[cfg(feature = "monitor")]
+pub mod monitor;
+
 #[cfg(feature = "network")]
 pub mod network;
 
+#[cfg(feature = "numa")]
+pub mod numa;
+
+#[cfg(feature = "pressure")]
+pub mod pressure;
+
 #[cfg(feature = "process")]
 pub mod process;
 
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+
 #[cfg(feature = "sensors")]
 pub mod sensors;
 
+#[cfg(feature = "sink")]
+pub mod sink;
+
 cfg_if::cfg_if! {
 	if #[cfg(target_family = "unix")] {
 		mod unix;
 		use unix::*;
 	}
 }
+
+#[cfg(all(target_os = "linux", any(feature = "host", feature = "process")))]
+mod linux;
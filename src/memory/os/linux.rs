@@ -1,6 +1,10 @@
+use std::collections::HashMap;
+
 use crate::memory::VirtualMemory;
-use crate::Bytes;
+use crate::{Bytes, Count};
 
+/// Fields that `/proc/meminfo` lacks are `None`, either because the kernel is too old or because
+/// it is emulated, e.g. by gVisor or in some containers.
 pub trait VirtualMemoryExt {
 	/// Temporary storage for raw disk blocks.
 	fn buffers(&self) -> Bytes;
@@ -9,9 +13,84 @@ pub trait VirtualMemoryExt {
 	fn cached(&self) -> Bytes;
 
 	/// Amount of memory consumed by tmpfs filesystems.
-	fn shared(&self) -> Bytes;
+	///
+	/// `None` before Linux 2.6.32.
+	fn shared(&self) -> Option<Bytes>;
+
+	/// In-kernel data structures cache.
+	fn slab(&self) -> Option<Bytes>;
+
+	/// Part of the slab that might be reclaimed, such as caches.
+	///
+	/// `None` before Linux 2.6.19.
+	fn slab_reclaimable(&self) -> Option<Bytes>;
+
+	/// Part of the slab that cannot be reclaimed under memory pressure.
+	///
+	/// `None` before Linux 2.6.19.
+	fn slab_unreclaimable(&self) -> Option<Bytes>;
+
+	/// Memory waiting to be written back to the disk.
+	fn dirty(&self) -> Option<Bytes>;
+
+	/// Memory actively being written back to the disk.
+	fn writeback(&self) -> Option<Bytes>;
+
+	/// Files which have been mapped into memory, such as libraries.
+	fn mapped(&self) -> Option<Bytes>;
+
+	/// Memory currently allocated on the system, even if not yet used.
+	fn committed_as(&self) -> Option<Bytes>;
+
+	/// Total memory that can be allocated under the current overcommit policy.
+	///
+	/// `None` before Linux 2.6.10.
+	fn commit_limit(&self) -> Option<Bytes>;
+
+	/// Size of the default huge page pool in pages.
+	///
+	/// `None` if the kernel was built without huge page support.
+	fn hugepages_total(&self) -> Option<Count>;
+
+	/// Huge pages of the default pool not yet allocated.
+	///
+	/// `None` if the kernel was built without huge page support.
+	fn hugepages_free(&self) -> Option<Count>;
+
+	/// Default huge page size.
+	///
+	/// `None` if the kernel was built without huge page support.
+	fn hugepage_size(&self) -> Option<Bytes>;
+
+	/// Non-file backed pages mapped into userspace page tables.
+	///
+	/// `None` before Linux 2.6.18.
+	fn anon_pages(&self) -> Option<Bytes>;
 
-	fn slab(&self) -> Bytes;
+	/// File backed pages on the active and inactive lists.
+	///
+	/// `None` before Linux 2.6.28.
+	fn file_pages(&self) -> Option<Bytes>;
+
+	/// Memory used by the kernel stacks of all tasks.
+	///
+	/// `None` before Linux 2.6.32.
+	fn kernel_stack(&self) -> Option<Bytes>;
+
+	/// Memory dedicated to the lowest level of page tables.
+	fn page_tables(&self) -> Option<Bytes>;
+
+	/// Total size of the vmalloc memory area.
+	fn vmalloc_total(&self) -> Option<Bytes>;
+
+	/// Used part of the vmalloc memory area.
+	fn vmalloc_used(&self) -> Option<Bytes>;
+
+	/// Every field of `/proc/meminfo`.
+	///
+	/// Values given in kB are converted to bytes, others such as the huge page counts are left
+	/// as is.
+	fn meminfo(&self) -> &HashMap<String, u64>;
 }
 
 impl VirtualMemoryExt for VirtualMemory {
@@ -23,11 +102,79 @@ impl VirtualMemoryExt for VirtualMemory {
 		self.cached
 	}
 
-	fn shared(&self) -> Bytes {
+	fn shared(&self) -> Option<Bytes> {
 		self.shared
 	}
 
-	fn slab(&self) -> Bytes {
+	fn slab(&self) -> Option<Bytes> {
 		self.slab
 	}
+
+	fn slab_reclaimable(&self) -> Option<Bytes> {
+		self.slab_reclaimable
+	}
+
+	fn slab_unreclaimable(&self) -> Option<Bytes> {
+		self.slab_unreclaimable
+	}
+
+	fn dirty(&self) -> Option<Bytes> {
+		self.dirty
+	}
+
+	fn writeback(&self) -> Option<Bytes> {
+		self.writeback
+	}
+
+	fn mapped(&self) -> Option<Bytes> {
+		self.mapped
+	}
+
+	fn committed_as(&self) -> Option<Bytes> {
+		self.committed_as
+	}
+
+	fn commit_limit(&self) -> Option<Bytes> {
+		self.commit_limit
+	}
+
+	fn hugepages_total(&self) -> Option<Count> {
+		self.hugepages_total
+	}
+
+	fn hugepages_free(&self) -> Option<Count> {
+		self.hugepages_free
+	}
+
+	fn hugepage_size(&self) -> Option<Bytes> {
+		self.hugepage_size
+	}
+
+	fn anon_pages(&self) -> Option<Bytes> {
+		self.anon_pages
+	}
+
+	fn file_pages(&self) -> Option<Bytes> {
+		self.file_pages
+	}
+
+	fn kernel_stack(&self) -> Option<Bytes> {
+		self.kernel_stack
+	}
+
+	fn page_tables(&self) -> Option<Bytes> {
+		self.page_tables
+	}
+
+	fn vmalloc_total(&self) -> Option<Bytes> {
+		self.vmalloc_total
+	}
+
+	fn vmalloc_used(&self) -> Option<Bytes> {
+		self.vmalloc_used
+	}
+
+	fn meminfo(&self) -> &HashMap<String, u64> {
+		&self.meminfo
+	}
 }
@@ -16,7 +16,7 @@ pub struct SwapMemory {
 }
 
 impl SwapMemory {
-	/// Amount of total swap memory.
+	/// Amount of total swap memory, 0 if swap is disabled.
 	pub fn total(&self) -> Bytes {
 		self.total
 	}
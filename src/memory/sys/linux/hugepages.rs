@@ -0,0 +1,188 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::{read_dir, read_file, Bytes, Count, Error, Result};
+
+const SYS_HUGEPAGES: &str = "/sys/kernel/mm/hugepages";
+const SYS_THP_ENABLED: &str = "/sys/kernel/mm/transparent_hugepage/enabled";
+
+/// The pool of preallocated huge pages of one size.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HugepagePool {
+	pub(crate) size: Bytes,
+	pub(crate) nr: Count,
+	pub(crate) free: Count,
+	pub(crate) resv: Count,
+	pub(crate) surplus: Count,
+	pub(crate) overcommit: Count,
+}
+
+impl HugepagePool {
+	/// Size of a single page.
+	pub fn size(&self) -> Bytes {
+		self.size
+	}
+
+	/// Pages in the pool.
+	pub fn nr(&self) -> Count {
+		self.nr
+	}
+
+	/// Pages not yet allocated.
+	pub fn free(&self) -> Count {
+		self.free
+	}
+
+	/// Pages promised to mappings but not yet faulted in.
+	pub fn resv(&self) -> Count {
+		self.resv
+	}
+
+	/// Pages allocated beyond `nr` through overcommit.
+	pub fn surplus(&self) -> Count {
+		self.surplus
+	}
+
+	/// Maximum number of surplus pages.
+	pub fn overcommit(&self) -> Count {
+		self.overcommit
+	}
+
+	/// Pages in use, not counting reserved ones.
+	pub fn used(&self) -> Count {
+		self.nr.saturating_sub(self.free)
+	}
+}
+
+fn read_count(path: &Path, name: &str) -> Result<Count> {
+	let path = path.join(name);
+	let contents = read_file(&path)?;
+
+	contents.trim().parse().map_err(|err| Error::ParseInt {
+		path,
+		contents,
+		source: err,
+	})
+}
+
+fn read_pool(size: Bytes, path: &Path) -> Result<HugepagePool> {
+	Ok(HugepagePool {
+		size,
+		nr: read_count(path, "nr_hugepages")?,
+		free: read_count(path, "free_hugepages")?,
+		resv: read_count(path, "resv_hugepages")?,
+		surplus: read_count(path, "surplus_hugepages")?,
+		overcommit: read_count(path, "nr_overcommit_hugepages")?,
+	})
+}
+
+/// Parses directory names like `hugepages-2048kB`.
+fn parse_pool_size(name: &str) -> Option<Bytes> {
+	let kib: u64 = name
+		.strip_prefix("hugepages-")?
+		.strip_suffix("kB")?
+		.parse()
+		.ok()?;
+
+	Some(kib * 1024)
+}
+
+/// Huge page pools of every supported size, smallest first.
+///
+/// Empty if the kernel was built without huge page support.
+pub fn hugepage_pools() -> Result<Vec<HugepagePool>> {
+	let entries = match read_dir(SYS_HUGEPAGES) {
+		Ok(entries) => entries,
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => {
+			return Ok(Vec::new());
+		}
+		Err(err) => return Err(err),
+	};
+
+	let mut pools = entries
+		.iter()
+		.filter_map(|entry| {
+			let size = parse_pool_size(entry.file_name().to_str()?)?;
+
+			Some(read_pool(size, &entry.path()))
+		})
+		.collect::<Result<Vec<_>>>()?;
+	pools.sort_by_key(|pool| pool.size);
+
+	Ok(pools)
+}
+
+/// When transparent huge pages are used for anonymous memory.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThpMode {
+	/// For every large enough mapping.
+	Always,
+	/// Only for mappings marked with `madvise(MADV_HUGEPAGE)`.
+	Madvise,
+	Never,
+}
+
+fn parse_thp_mode(contents: &str) -> Result<ThpMode> {
+	let selected = contents
+		.split_whitespace()
+		.find_map(|mode| mode.strip_prefix('[')?.strip_suffix(']'));
+
+	match selected {
+		Some("always") => Ok(ThpMode::Always),
+		Some("madvise") => Ok(ThpMode::Madvise),
+		Some("never") => Ok(ThpMode::Never),
+		_ => Err(Error::MissingData {
+			path: SYS_THP_ENABLED.into(),
+			contents: contents.to_string(),
+		}),
+	}
+}
+
+/// The system-wide transparent huge page mode.
+///
+/// Fails if the kernel was built without transparent huge page support.
+pub fn thp_mode() -> Result<ThpMode> {
+	parse_thp_mode(&read_file(SYS_THP_ENABLED)?)
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_pool_size() {
+		assert_eq!(parse_pool_size("hugepages-2048kB"), Some(2 * 1024 * 1024));
+		assert_eq!(
+			parse_pool_size("hugepages-1048576kB"),
+			Some(1024 * 1024 * 1024)
+		);
+		assert_eq!(parse_pool_size("uevent"), None);
+	}
+
+	#[test]
+	fn test_parse_thp_mode() {
+		assert_eq!(
+			parse_thp_mode("always [madvise] never\n").unwrap(),
+			ThpMode::Madvise
+		);
+		assert_eq!(
+			parse_thp_mode("[always] madvise never\n").unwrap(),
+			ThpMode::Always
+		);
+		assert!(parse_thp_mode("always madvise never\n").is_err());
+	}
+
+	#[test]
+	fn test_hugepage_pools() {
+		for pool in hugepage_pools().unwrap() {
+			assert!(pool.nr() >= pool.free());
+		}
+	}
+}
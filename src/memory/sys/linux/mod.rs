@@ -1,7 +1,13 @@
 mod common;
+mod hugepages;
+mod swap_devices;
 mod swap_memory;
 mod virtual_memory;
+mod vmstat;
 
 pub(crate) use common::*;
+pub use hugepages::*;
+pub use swap_devices::*;
 pub use swap_memory::*;
 pub use virtual_memory::*;
+pub use vmstat::*;
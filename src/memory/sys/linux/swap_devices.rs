@@ -0,0 +1,290 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::path::{Path, PathBuf};
+
+use crate::utils::unescape_octal;
+use crate::{read_file, Bytes, Count, Error, FloatCount, Result};
+
+const PROC_SWAPS: &str = "/proc/swaps";
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapKind {
+	Partition,
+	File,
+	/// Compressed swap in memory, a block device like `/dev/zram0`.
+	Zram,
+}
+
+/// Memory statistics of a zram device from `/sys/block/zram*/mm_stat`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZramStats {
+	pub(crate) orig_data_size: Bytes,
+	pub(crate) compr_data_size: Bytes,
+	pub(crate) mem_used_total: Bytes,
+	pub(crate) mem_limit: Bytes,
+	pub(crate) mem_used_max: Bytes,
+	pub(crate) same_pages: Count,
+	pub(crate) pages_compacted: Count,
+	pub(crate) huge_pages: Option<Count>,
+}
+
+impl ZramStats {
+	/// Uncompressed size of the stored data.
+	pub fn orig_data_size(&self) -> Bytes {
+		self.orig_data_size
+	}
+
+	/// Compressed size of the stored data.
+	pub fn compr_data_size(&self) -> Bytes {
+		self.compr_data_size
+	}
+
+	/// Memory allocated for the compressed data, including fragmentation and metadata.
+	pub fn mem_used_total(&self) -> Bytes {
+		self.mem_used_total
+	}
+
+	/// Maximum memory the device may use, 0 if unlimited.
+	pub fn mem_limit(&self) -> Bytes {
+		self.mem_limit
+	}
+
+	/// Highest `mem_used_total` so far.
+	pub fn mem_used_max(&self) -> Bytes {
+		self.mem_used_max
+	}
+
+	/// Pages filled with the same value, which are stored without allocating memory.
+	pub fn same_pages(&self) -> Count {
+		self.same_pages
+	}
+
+	/// Pages freed by compaction.
+	pub fn pages_compacted(&self) -> Count {
+		self.pages_compacted
+	}
+
+	/// Pages that could not be compressed and are stored as is.
+	///
+	/// `None` before Linux 4.19.
+	pub fn huge_pages(&self) -> Option<Count> {
+		self.huge_pages
+	}
+
+	/// Uncompressed size divided by the compressed size.
+	pub fn compression_ratio(&self) -> FloatCount {
+		if self.compr_data_size == 0 {
+			return 0.0;
+		}
+
+		self.orig_data_size as FloatCount / self.compr_data_size as FloatCount
+	}
+
+	/// Uncompressed size divided by the memory actually used, the memory saved in practice.
+	pub fn effective_ratio(&self) -> FloatCount {
+		if self.mem_used_total == 0 {
+			return 0.0;
+		}
+
+		self.orig_data_size as FloatCount / self.mem_used_total as FloatCount
+	}
+}
+
+fn parse_zram_stats(contents: &str, path: &Path) -> Result<ZramStats> {
+	let fields = contents
+		.split_whitespace()
+		.map(|field| {
+			field.parse::<u64>().map_err(|err| Error::ParseInt {
+				path: path.into(),
+				contents: contents.to_string(),
+				source: err,
+			})
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	if fields.len() < 7 {
+		return Err(Error::MissingData {
+			path: path.into(),
+			contents: contents.to_string(),
+		});
+	}
+
+	Ok(ZramStats {
+		orig_data_size: fields[0],
+		compr_data_size: fields[1],
+		mem_used_total: fields[2],
+		mem_limit: fields[3],
+		mem_used_max: fields[4],
+		same_pages: fields[5],
+		pages_compacted: fields[6],
+		huge_pages: fields.get(7).copied(),
+	})
+}
+
+/// Statistics of a zram device by name, e.g. `zram0`.
+///
+/// Requires Linux 4.1.
+pub fn zram_stats(name: &str) -> Result<ZramStats> {
+	let path = PathBuf::from("/sys/block").join(name).join("mm_stat");
+
+	parse_zram_stats(&read_file(&path)?, &path)
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapDevice {
+	pub(crate) path: PathBuf,
+	pub(crate) kind: SwapKind,
+	pub(crate) size: Bytes,
+	pub(crate) used: Bytes,
+	pub(crate) priority: i32,
+	pub(crate) zram_stats: Option<ZramStats>,
+}
+
+impl SwapDevice {
+	/// Path of the swap partition or file.
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	pub fn kind(&self) -> SwapKind {
+		self.kind
+	}
+
+	pub fn size(&self) -> Bytes {
+		self.size
+	}
+
+	pub fn used(&self) -> Bytes {
+		self.used
+	}
+
+	/// Devices with a higher priority are used first.
+	pub fn priority(&self) -> i32 {
+		self.priority
+	}
+
+	/// Compression statistics of zram devices, `None` for other kinds or before Linux 4.1.
+	pub fn zram_stats(&self) -> Option<&ZramStats> {
+		self.zram_stats.as_ref()
+	}
+}
+
+fn zram_name(path: &Path) -> Option<&str> {
+	path.strip_prefix("/dev")
+		.ok()?
+		.to_str()
+		.filter(|name| name.starts_with("zram"))
+}
+
+fn parse_swaps(contents: &str) -> Result<Vec<SwapDevice>> {
+	let missing = |line: &str| Error::MissingData {
+		path: PROC_SWAPS.into(),
+		contents: line.to_string(),
+	};
+
+	// skip the header
+	contents
+		.lines()
+		.skip(1)
+		.filter(|line| !line.trim().is_empty())
+		.map(|line| {
+			// the path comes first and has its whitespace escaped, so splitting is safe
+			let fields: Vec<&str> = line.split_whitespace().collect();
+			if fields.len() < 5 {
+				return Err(missing(line));
+			}
+
+			let parse_err = |err| Error::ParseInt {
+				path: PROC_SWAPS.into(),
+				contents: line.to_string(),
+				source: err,
+			};
+			let parse_kib = |field: &str| -> Result<Bytes> {
+				Ok(field.parse::<u64>().map_err(parse_err)? * 1024)
+			};
+
+			let path = PathBuf::from(unescape_octal(fields[0]));
+			let kind = match (fields[1], zram_name(&path)) {
+				(_, Some(_)) => SwapKind::Zram,
+				("partition", None) => SwapKind::Partition,
+				("file", None) => SwapKind::File,
+				_ => return Err(missing(line)),
+			};
+
+			Ok(SwapDevice {
+				path,
+				kind,
+				size: parse_kib(fields[2])?,
+				used: parse_kib(fields[3])?,
+				priority: fields[4].parse().map_err(parse_err)?,
+				zram_stats: None,
+			})
+		})
+		.collect()
+}
+
+/// Swap partitions, files and zram devices in use, from `/proc/swaps`.
+pub fn swap_devices() -> Result<Vec<SwapDevice>> {
+	let mut swap_devices = parse_swaps(&read_file(PROC_SWAPS)?)?;
+
+	for swap_device in &mut swap_devices {
+		if let Some(name) = zram_name(&swap_device.path) {
+			swap_device.zram_stats = zram_stats(name).ok();
+		}
+	}
+
+	Ok(swap_devices)
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_swaps() {
+		let contents = "Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority\n\
+		                /dev/sda2                               partition\t8388604\t\t1024\t\t-2\n\
+		                /swap\\040file                           file\t\t1048572\t\t0\t\t-3\n\
+		                /dev/zram0                              partition\t4046844\t\t81920\t\t100\n";
+		let swap_devices = parse_swaps(contents).unwrap();
+
+		assert_eq!(swap_devices.len(), 3);
+		assert_eq!(swap_devices[0].kind(), SwapKind::Partition);
+		assert_eq!(swap_devices[0].size(), 8388604 * 1024);
+		assert_eq!(swap_devices[0].used(), 1024 * 1024);
+		assert_eq!(swap_devices[0].priority(), -2);
+		assert_eq!(swap_devices[1].path(), Path::new("/swap file"));
+		assert_eq!(swap_devices[1].kind(), SwapKind::File);
+		assert_eq!(swap_devices[2].kind(), SwapKind::Zram);
+		assert_eq!(swap_devices[2].priority(), 100);
+
+		assert!(parse_swaps("Filename Type Size Used Priority\n")
+			.unwrap()
+			.is_empty());
+	}
+
+	#[test]
+	fn test_parse_zram_stats() {
+		let path = Path::new("/sys/block/zram0/mm_stat");
+		let stats = parse_zram_stats(
+			"  4096000  1024000  1228800        0  1228800       10        0        3\n",
+			path,
+		)
+		.unwrap();
+
+		assert_eq!(stats.compression_ratio(), 4.0);
+		assert_eq!(stats.huge_pages(), Some(3));
+
+		// Linux 4.1
+		let stats = parse_zram_stats("0 0 0 0 0 0 0\n", path).unwrap();
+		assert_eq!(stats.huge_pages(), None);
+		assert_eq!(stats.compression_ratio(), 0.0);
+	}
+}
@@ -1,17 +1,18 @@
-use crate::memory::{make_map, SwapMemory};
+use crate::memory::{make_map, parse_vmstat, vmstat_get, SwapMemory};
 use crate::utils::u64_percent;
 use crate::{read_file, Error, Result};
 
 const PROC_MEMINFO: &str = "/proc/meminfo";
 const PROC_VMSTAT: &str = "/proc/vmstat";
 
-// TODO: return an option for when swap is disabled?
+/// All values but `swapped_in` and `swapped_out` are 0 if swap is disabled. These keep counting
+/// the pages that were swapped before swap was turned off.
 pub fn swap_memory() -> Result<SwapMemory> {
 	let meminfo_contents = read_file(PROC_MEMINFO)?;
 	let meminfo = make_map(&meminfo_contents, PROC_MEMINFO)?;
 
 	let vmstat_contents = read_file(PROC_VMSTAT)?;
-	let vmstat = make_map(&vmstat_contents, PROC_VMSTAT)?;
+	let vmstat = parse_vmstat(&vmstat_contents)?;
 
 	let meminfo_get = |key: &str| -> Result<u64> {
 		meminfo.get(key).copied().ok_or(Error::MissingData {
@@ -19,18 +20,13 @@ pub fn swap_memory() -> Result<SwapMemory> {
 			contents: meminfo_contents.clone(),
 		})
 	};
-	let vmstat_get = |key: &str| -> Result<u64> {
-		vmstat.get(key).copied().ok_or(Error::MissingData {
-			path: PROC_VMSTAT.into(),
-			contents: vmstat_contents.clone(),
-		})
-	};
 
 	let total = meminfo_get("SwapTotal")?;
 	let free = meminfo_get("SwapFree")?;
 
-	let swapped_in = vmstat_get("pswpin")?;
-	let swapped_out = vmstat_get("pswpout")?;
+	// in pages, unlike the other platforms
+	let swapped_in = vmstat_get(&vmstat, &vmstat_contents, "pswpin")?;
+	let swapped_out = vmstat_get(&vmstat, &vmstat_contents, "pswpout")?;
 
 	let used = total - free;
 	// total will be 0 if swap is disabled
@@ -1,38 +1,100 @@
 use crate::memory::{make_map, VirtualMemory};
-use crate::{read_file, Error, Result};
+use crate::{read_file, Error, Result, PAGE_SIZE};
 
 const PROC_MEMINFO: &str = "/proc/meminfo";
+const PROC_ZONEINFO: &str = "/proc/zoneinfo";
 
-// TODO: some of this stuff relies on a kernel version greater than 2.6
-pub fn virtual_memory() -> Result<VirtualMemory> {
-	let contents = read_file(PROC_MEMINFO)?;
-	let meminfo = make_map(&contents, PROC_MEMINFO)?;
+/// Sum of the low watermarks of all zones, in bytes.
+fn parse_watermark_low(zoneinfo: &str) -> u64 {
+	zoneinfo
+		.lines()
+		.filter_map(|line| {
+			let mut fields = line.split_whitespace();
+			match fields.next() {
+				Some("low") => fields.next()?.parse::<u64>().ok(),
+				_ => None,
+			}
+		})
+		.sum::<u64>()
+		* *PAGE_SIZE
+}
+
+/// Estimates the available memory before Linux 3.14, the way psutil and procps do.
+///
+/// Mirrors the kernel's own calculation of `MemAvailable`, falling back to free plus cached
+/// memory if the needed fields or `/proc/zoneinfo` are missing.
+fn estimate_available(
+	free: u64,
+	cached: u64,
+	file_pages: Option<u64>,
+	slab_reclaimable: Option<u64>,
+	zoneinfo: Option<&str>,
+) -> u64 {
+	let (pagecache, slab_reclaimable, zoneinfo) = match (file_pages, slab_reclaimable, zoneinfo) {
+		(Some(file_pages), Some(slab_reclaimable), Some(zoneinfo)) => {
+			(file_pages, slab_reclaimable, zoneinfo)
+		}
+		_ => return free + cached,
+	};
+	let watermark_low = parse_watermark_low(zoneinfo);
+
+	let available = free as i64 - watermark_low as i64
+		+ (pagecache - (pagecache / 2).min(watermark_low)) as i64
+		+ (slab_reclaimable - (slab_reclaimable / 2).min(watermark_low)) as i64;
+
+	available.max(0) as u64
+}
+
+/// `zoneinfo` is only needed if `MemAvailable` is missing.
+fn parse_meminfo(contents: &str, zoneinfo: Option<&str>) -> Result<VirtualMemory> {
+	let meminfo = make_map(contents, PROC_MEMINFO)?;
 
 	let get = |key: &str| -> Result<u64> {
 		meminfo.get(key).copied().ok_or(Error::MissingData {
 			path: PROC_MEMINFO.into(),
-			contents: contents.clone(),
+			contents: contents.to_string(),
 		})
 	};
+	// fields that not every supported kernel version or sandbox, like gVisor, has
+	let get_opt = |key: &str| -> Option<u64> { meminfo.get(key).copied() };
 
 	let total = get("MemTotal")?;
-	// since Linux 3.14
-	let available = get("MemAvailable")?;
 	let free = get("MemFree")?;
 	let active = get("Active")?;
 	let inactive = get("Inactive")?;
 	let buffers = get("Buffers")?;
+	// since Linux 2.6.19
+	let slab_reclaimable = get_opt("SReclaimable");
+	let slab_unreclaimable = get_opt("SUnreclaim");
 	// "free" cmdline utility sums reclaimable to cached.
 	// Older versions of procps used to add slab memory instead.
 	// This got changed in:
 	// https://gitlab.com/procps-ng/procps/commit/05d751c4f076a2f0118b914c5e51cfbb4762ad8e
-	// SReclaimable available since Linux 2.6.19
-	let cached = get("Cached")? + get("SReclaimable")?;
-	// since Linux 2.6.32
-	let shared = get("Shmem")?;
-	let slab = 0; // TODO
+	let cached = get("Cached")? + slab_reclaimable.unwrap_or(0);
+	// since Linux 2.6.28
+	let file_pages = match (get_opt("Active(file)"), get_opt("Inactive(file)")) {
+		(Some(active_file), Some(inactive_file)) => Some(active_file + inactive_file),
+		_ => None,
+	};
+	// since Linux 3.14
+	let available = match get_opt("MemAvailable") {
+		Some(available) => available,
+		None => {
+			let estimate = estimate_available(free, cached, file_pages, slab_reclaimable, zoneinfo);
+			// can happen in containers, see https://github.com/giampaolo/psutil/issues/1915
+			if estimate > total {
+				free
+			} else {
+				estimate
+			}
+		}
+	};
 
-	let used = total - free - cached - buffers;
+	// some kernels report more cached memory than is in use, e.g. in LXC containers
+	let used = match total.checked_sub(free + cached + buffers) {
+		Some(used) => used,
+		None => total.saturating_sub(free),
+	};
 	let percent = (((total as f64 - available as f64) / total as f64) * 100.0) as f32;
 
 	Ok(VirtualMemory {
@@ -45,7 +107,183 @@ pub fn virtual_memory() -> Result<VirtualMemory> {
 		inactive,
 		buffers,
 		cached,
-		shared,
-		slab,
+		// since Linux 2.6.32
+		shared: get_opt("Shmem"),
+		slab: get_opt("Slab"),
+		slab_reclaimable,
+		slab_unreclaimable,
+		dirty: get_opt("Dirty"),
+		writeback: get_opt("Writeback"),
+		mapped: get_opt("Mapped"),
+		committed_as: get_opt("Committed_AS"),
+		// since Linux 2.6.10
+		commit_limit: get_opt("CommitLimit"),
+		// only present if the kernel was built with CONFIG_HUGETLB_PAGE
+		hugepages_total: get_opt("HugePages_Total"),
+		hugepages_free: get_opt("HugePages_Free"),
+		hugepage_size: get_opt("Hugepagesize"),
+		// since Linux 2.6.18
+		anon_pages: get_opt("AnonPages"),
+		file_pages,
+		// since Linux 2.6.32
+		kernel_stack: get_opt("KernelStack"),
+		page_tables: get_opt("PageTables"),
+		vmalloc_total: get_opt("VmallocTotal"),
+		vmalloc_used: get_opt("VmallocUsed"),
+		meminfo: meminfo
+			.iter()
+			.map(|(&key, &value)| (key.to_string(), value))
+			.collect(),
 	})
 }
+
+pub fn virtual_memory() -> Result<VirtualMemory> {
+	let contents = read_file(PROC_MEMINFO)?;
+	// since Linux 2.6.13, only needed to estimate the available memory on old kernels
+	let zoneinfo = if contents.contains("MemAvailable:") {
+		None
+	} else {
+		read_file(PROC_ZONEINFO).ok()
+	};
+
+	parse_meminfo(&contents, zoneinfo.as_deref())
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use crate::memory::os::linux::VirtualMemoryExt;
+
+	const MEMINFO: &str = "MemTotal:        6147400 kB
+MemFree:         3107588 kB
+MemAvailable:    5585548 kB
+Buffers:           63488 kB
+Cached:          2598960 kB
+Active:           777448 kB
+Inactive:        2046448 kB
+Active(file):     777436 kB
+Inactive(file):  1875528 kB
+Dirty:            251044 kB
+Writeback:             0 kB
+AnonPages:        170920 kB
+Mapped:           140216 kB
+Shmem:              9484 kB
+Slab:              98536 kB
+SReclaimable:      75832 kB
+SUnreclaim:        22704 kB
+KernelStack:        1152 kB
+PageTables:         1996 kB
+CommitLimit:     3073700 kB
+Committed_AS:     335892 kB
+VmallocTotal:   34359738367 kB
+VmallocUsed:       15880 kB
+HugePages_Total:       4
+HugePages_Free:        3
+Hugepagesize:       2048 kB
+";
+
+	#[test]
+	fn test_virtual_memory() {
+		let vm = virtual_memory().unwrap();
+		assert!(vm.total() > 0);
+		assert!(vm.slab().unwrap_or(0) >= vm.slab_reclaimable().unwrap_or(0));
+	}
+
+	#[test]
+	fn test_parse_meminfo() {
+		let vm = parse_meminfo(MEMINFO, None).unwrap();
+
+		assert_eq!(vm.total(), 6147400 * 1024);
+		assert_eq!(vm.slab(), Some(98536 * 1024));
+		assert_eq!(vm.slab_reclaimable(), Some(75832 * 1024));
+		assert_eq!(vm.slab_unreclaimable(), Some(22704 * 1024));
+		assert_eq!(vm.cached(), (2598960 + 75832) * 1024);
+		assert_eq!(vm.dirty(), Some(251044 * 1024));
+		assert_eq!(vm.committed_as(), Some(335892 * 1024));
+		assert_eq!(vm.file_pages(), Some((777436 + 1875528) * 1024));
+		assert_eq!(vm.hugepages_total(), Some(4));
+		assert_eq!(vm.hugepages_free(), Some(3));
+		assert_eq!(vm.hugepage_size(), Some(2048 * 1024));
+		assert_eq!(vm.meminfo()["HugePages_Total"], 4);
+		assert_eq!(vm.meminfo()["Mapped"], 140216 * 1024);
+	}
+
+	// Linux 2.6.18: no MemAvailable, SReclaimable, Shmem, KernelStack or file LRU lists
+	const MEMINFO_2_6_18: &str = "MemTotal:      1024000 kB
+MemFree:        200000 kB
+Buffers:         50000 kB
+Cached:         300000 kB
+SwapCached:          0 kB
+Active:         400000 kB
+Inactive:       250000 kB
+Dirty:             100 kB
+Writeback:           0 kB
+AnonPages:      150000 kB
+Mapped:          40000 kB
+Slab:            30000 kB
+PageTables:       2000 kB
+CommitLimit:    512000 kB
+Committed_AS:   300000 kB
+VmallocTotal:   100000 kB
+VmallocUsed:      5000 kB
+";
+
+	#[test]
+	fn test_parse_meminfo_old_kernel() {
+		let vm = parse_meminfo(MEMINFO_2_6_18, None).unwrap();
+
+		assert_eq!(vm.available(), (200000 + 300000) * 1024);
+		assert_eq!(vm.cached(), 300000 * 1024);
+		assert_eq!(vm.used(), (1024000 - 200000 - 300000 - 50000) * 1024);
+		assert_eq!(vm.shared(), None);
+		assert_eq!(vm.slab_reclaimable(), None);
+		assert_eq!(vm.kernel_stack(), None);
+		assert_eq!(vm.file_pages(), None);
+		assert_eq!(vm.anon_pages(), Some(150000 * 1024));
+		assert_eq!(vm.hugepages_total(), None);
+	}
+
+	// gVisor and some container runtimes emulate only the basic fields
+	const MEMINFO_MINIMAL: &str = "MemTotal:        2097152 kB
+MemFree:         1048576 kB
+MemAvailable:    1572864 kB
+Buffers:               0 kB
+Cached:           524288 kB
+Active:           262144 kB
+Inactive:         262144 kB
+";
+
+	#[test]
+	fn test_parse_meminfo_minimal() {
+		let vm = parse_meminfo(MEMINFO_MINIMAL, None).unwrap();
+
+		assert_eq!(vm.total(), 2097152 * 1024);
+		assert_eq!(vm.available(), 1572864 * 1024);
+		assert_eq!(vm.slab(), None);
+		assert_eq!(vm.dirty(), None);
+		assert_eq!(vm.writeback(), None);
+		assert_eq!(vm.mapped(), None);
+		assert_eq!(vm.committed_as(), None);
+		assert_eq!(vm.page_tables(), None);
+		assert_eq!(vm.vmalloc_total(), None);
+		assert_eq!(vm.vmalloc_used(), None);
+		assert_eq!(vm.shared(), None);
+	}
+
+	#[test]
+	fn test_estimate_available() {
+		let zoneinfo = "Node 0, zone   Normal\n  pages free     1000\n        min      10\n        low      20\n        high     30\n";
+		let watermark_low = 20 * *PAGE_SIZE;
+		assert_eq!(parse_watermark_low(zoneinfo), watermark_low);
+
+		let gib = 1024 * 1024 * 1024;
+		assert_eq!(
+			estimate_available(gib, gib, Some(gib), Some(gib), Some(zoneinfo)),
+			gib - watermark_low + (gib - watermark_low) * 2
+		);
+		assert_eq!(
+			estimate_available(gib, gib, None, Some(gib), Some(zoneinfo)),
+			2 * gib
+		);
+	}
+}
@@ -0,0 +1,325 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::memory::make_map;
+use crate::{read_file, Count, Error, FloatCount, Result};
+
+const PROC_VMSTAT: &str = "/proc/vmstat";
+
+/// Whether a counter belongs to the pages scanned by reclaim.
+///
+/// Before Linux 4.8 the counters are split per zone, e.g. `pgscan_kswapd_normal`. Since
+/// Linux 5.8, `pgscan_anon` and `pgscan_file` split the same pages by type and are skipped to
+/// not count them twice.
+fn is_reclaim_counter(name: &str, prefix: &str) -> bool {
+	match name.strip_prefix(prefix) {
+		Some(rest) => {
+			(rest.starts_with("kswapd") || rest.starts_with("direct") || rest == "khugepaged")
+				&& rest != "direct_throttle"
+		}
+		None => false,
+	}
+}
+
+fn sum_reclaim_counters<'a, I, T>(counters: I, prefix: &str) -> T
+where
+	I: IntoIterator<Item = (&'a String, &'a T)>,
+	T: Copy + std::iter::Sum<T> + 'a,
+{
+	counters
+		.into_iter()
+		.filter(|(name, _)| is_reclaim_counter(name, prefix))
+		.map(|(_, &value)| value)
+		.sum()
+}
+
+/// Virtual memory statistics from `/proc/vmstat`, Linux only.
+///
+/// All counters are in pages or events since boot.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug)]
+pub struct VmStat {
+	pub(crate) counters: HashMap<String, Count>,
+}
+
+impl VmStat {
+	/// Every field of `/proc/vmstat`, including the `nr_*` gauges.
+	pub fn counters(&self) -> &HashMap<String, Count> {
+		&self.counters
+	}
+
+	pub fn get(&self, name: &str) -> Option<Count> {
+		self.counters.get(name).copied()
+	}
+
+	/// Pages paged in from disk.
+	pub fn pgpgin(&self) -> Count {
+		self.get("pgpgin").unwrap_or_default()
+	}
+
+	/// Pages paged out to disk.
+	pub fn pgpgout(&self) -> Count {
+		self.get("pgpgout").unwrap_or_default()
+	}
+
+	/// Pages swapped in.
+	pub fn pswpin(&self) -> Count {
+		self.get("pswpin").unwrap_or_default()
+	}
+
+	/// Pages swapped out.
+	pub fn pswpout(&self) -> Count {
+		self.get("pswpout").unwrap_or_default()
+	}
+
+	/// Page faults, minor and major.
+	pub fn pgfault(&self) -> Count {
+		self.get("pgfault").unwrap_or_default()
+	}
+
+	/// Page faults that required disk io.
+	pub fn pgmajfault(&self) -> Count {
+		self.get("pgmajfault").unwrap_or_default()
+	}
+
+	/// Pages scanned by kswapd, direct reclaim and khugepaged.
+	pub fn pgscan(&self) -> Count {
+		sum_reclaim_counters(&self.counters, "pgscan_")
+	}
+
+	/// Pages reclaimed by kswapd, direct reclaim and khugepaged.
+	pub fn pgsteal(&self) -> Count {
+		sum_reclaim_counters(&self.counters, "pgsteal_")
+	}
+
+	/// Processes killed by the OOM killer.
+	///
+	/// `None` before Linux 4.13.
+	pub fn oom_kill(&self) -> Option<Count> {
+		self.get("oom_kill")
+	}
+
+	/// Huge pages allocated on a page fault.
+	///
+	/// `None` if the kernel was built without transparent huge page support.
+	pub fn thp_fault_alloc(&self) -> Option<Count> {
+		self.get("thp_fault_alloc")
+	}
+
+	/// Page faults that fell back to regular pages as no huge page could be allocated.
+	///
+	/// `None` if the kernel was built without transparent huge page support.
+	pub fn thp_fault_fallback(&self) -> Option<Count> {
+		self.get("thp_fault_fallback")
+	}
+
+	/// Huge pages allocated by khugepaged to collapse regular pages.
+	///
+	/// `None` if the kernel was built without transparent huge page support.
+	pub fn thp_collapse_alloc(&self) -> Option<Count> {
+		self.get("thp_collapse_alloc")
+	}
+
+	/// Huge pages split into regular pages.
+	///
+	/// `None` if the kernel was built without transparent huge page support.
+	pub fn thp_split_page(&self) -> Option<Count> {
+		self.get("thp_split_page")
+	}
+}
+
+pub(crate) fn parse_vmstat(contents: &str) -> Result<VmStat> {
+	let counters = make_map(contents, PROC_VMSTAT)?
+		.into_iter()
+		.map(|(name, value)| (name.to_string(), value))
+		.collect();
+
+	Ok(VmStat { counters })
+}
+
+pub fn vmstat() -> Result<VmStat> {
+	parse_vmstat(&read_file(PROC_VMSTAT)?)
+}
+
+pub(crate) fn vmstat_get(vmstat: &VmStat, contents: &str, name: &str) -> Result<Count> {
+	vmstat.get(name).ok_or(Error::MissingData {
+		path: PROC_VMSTAT.into(),
+		contents: contents.to_string(),
+	})
+}
+
+/// Per-second rates of the `/proc/vmstat` event counters between two calls of a
+/// [`VmStatCollector`].
+///
+/// The `nr_*` fields are current values rather than counters and have no rates.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug)]
+pub struct VmStatRates {
+	pub(crate) elapsed: Duration,
+	pub(crate) rates: HashMap<String, FloatCount>,
+}
+
+impl VmStatRates {
+	fn new(prev: &VmStat, current: &VmStat, elapsed: Duration) -> VmStatRates {
+		let rates = current
+			.counters
+			.iter()
+			.filter(|(name, _)| !name.starts_with("nr_"))
+			.filter_map(|(name, &value)| {
+				// counters can only be reset by a reboot, but might wrap on 32-bit systems
+				let delta = value.saturating_sub(*prev.counters.get(name)?);
+				let rate = if elapsed.is_zero() {
+					0.0
+				} else {
+					delta as FloatCount / elapsed.as_secs_f64()
+				};
+
+				Some((name.clone(), rate))
+			})
+			.collect();
+
+		VmStatRates { elapsed, rates }
+	}
+
+	/// Time between the two calls.
+	pub fn elapsed(&self) -> Duration {
+		self.elapsed
+	}
+
+	/// Rates of every event counter.
+	pub fn rates(&self) -> &HashMap<String, FloatCount> {
+		&self.rates
+	}
+
+	pub fn get(&self, name: &str) -> Option<FloatCount> {
+		self.rates.get(name).copied()
+	}
+
+	/// Pages paged in per second.
+	pub fn pgpgin(&self) -> FloatCount {
+		self.get("pgpgin").unwrap_or_default()
+	}
+
+	/// Pages paged out per second.
+	pub fn pgpgout(&self) -> FloatCount {
+		self.get("pgpgout").unwrap_or_default()
+	}
+
+	/// Pages swapped in per second.
+	pub fn pswpin(&self) -> FloatCount {
+		self.get("pswpin").unwrap_or_default()
+	}
+
+	/// Pages swapped out per second.
+	pub fn pswpout(&self) -> FloatCount {
+		self.get("pswpout").unwrap_or_default()
+	}
+
+	/// Page faults per second.
+	pub fn pgfault(&self) -> FloatCount {
+		self.get("pgfault").unwrap_or_default()
+	}
+
+	/// Major page faults per second.
+	pub fn pgmajfault(&self) -> FloatCount {
+		self.get("pgmajfault").unwrap_or_default()
+	}
+
+	/// Pages scanned by reclaim per second.
+	pub fn pgscan(&self) -> FloatCount {
+		sum_reclaim_counters(&self.rates, "pgscan_")
+	}
+
+	/// Pages reclaimed per second.
+	pub fn pgsteal(&self) -> FloatCount {
+		sum_reclaim_counters(&self.rates, "pgsteal_")
+	}
+
+	/// OOM kills per second, `None` before Linux 4.13.
+	pub fn oom_kill(&self) -> Option<FloatCount> {
+		self.get("oom_kill")
+	}
+}
+
+/// Get `VmStatRates` in non-blocking mode.
+///
+/// Example:
+///
+/// ```no_run
+/// let mut vmstat_collector = psutil::memory::VmStatCollector::new().unwrap();
+///
+/// std::thread::sleep(std::time::Duration::from_secs(1));
+/// let rates = vmstat_collector.vmstat_rates().unwrap();
+/// // pages reclaimed per page scanned, low values mean reclaim is struggling
+/// let efficiency = rates.pgsteal() / rates.pgscan();
+/// ```
+#[derive(Clone, Debug)]
+pub struct VmStatCollector {
+	vmstat: VmStat,
+	instant: Instant,
+}
+
+impl VmStatCollector {
+	/// Initialize the `VmStatCollector` so the method calls are ready to be used.
+	pub fn new() -> Result<VmStatCollector> {
+		Ok(VmStatCollector {
+			vmstat: vmstat()?,
+			instant: Instant::now(),
+		})
+	}
+
+	/// Returns the rates since the last time this was called or since `VmStatCollector::new()`
+	/// was called.
+	pub fn vmstat_rates(&mut self) -> Result<VmStatRates> {
+		let current = vmstat()?;
+		let instant = Instant::now();
+		let rates = VmStatRates::new(&self.vmstat, &current, instant - self.instant);
+
+		self.vmstat = current;
+		self.instant = instant;
+
+		Ok(rates)
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_pgscan() {
+		// Linux 3.10, per zone counters
+		let old = parse_vmstat(
+			"pgscan_kswapd_dma 1\npgscan_kswapd_normal 2\npgscan_direct_normal 4\n\
+			 pgscan_direct_throttle 100\npgsteal_kswapd_normal 1\n",
+		)
+		.unwrap();
+		assert_eq!(old.pgscan(), 7);
+		assert_eq!(old.pgsteal(), 1);
+
+		// Linux 6.1
+		let new = parse_vmstat(
+			"pgscan_kswapd 10\npgscan_direct 20\npgscan_khugepaged 1\n\
+			 pgscan_direct_throttle 100\npgscan_anon 5\npgscan_file 26\n",
+		)
+		.unwrap();
+		assert_eq!(new.pgscan(), 31);
+		assert_eq!(new.oom_kill(), None);
+	}
+
+	#[test]
+	fn test_vmstat_rates() {
+		let prev = parse_vmstat("nr_free_pages 100\npgfault 1000\npgscan_kswapd 0\n").unwrap();
+		let current = parse_vmstat("nr_free_pages 50\npgfault 3000\npgscan_kswapd 10\n").unwrap();
+		let rates = VmStatRates::new(&prev, &current, Duration::from_secs(2));
+
+		assert_eq!(rates.get("nr_free_pages"), None);
+		assert_eq!(rates.pgfault(), 1000.0);
+		assert_eq!(rates.pgscan(), 5.0);
+	}
+}
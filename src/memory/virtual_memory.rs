@@ -1,6 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(target_os = "linux")]
+use std::collections::HashMap;
+
 use crate::{Bytes, Percent};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -23,9 +26,43 @@ pub struct VirtualMemory {
 	#[cfg(target_os = "linux")]
 	pub(crate) cached: Bytes,
 	#[cfg(target_os = "linux")]
-	pub(crate) shared: Bytes,
+	pub(crate) shared: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) slab: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) slab_reclaimable: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) slab_unreclaimable: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) dirty: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) writeback: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) mapped: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) committed_as: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) commit_limit: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) hugepages_total: Option<u64>,
+	#[cfg(target_os = "linux")]
+	pub(crate) hugepages_free: Option<u64>,
+	#[cfg(target_os = "linux")]
+	pub(crate) hugepage_size: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) anon_pages: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) file_pages: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) kernel_stack: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) page_tables: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) vmalloc_total: Option<Bytes>,
+	#[cfg(target_os = "linux")]
+	pub(crate) vmalloc_used: Option<Bytes>,
 	#[cfg(target_os = "linux")]
-	pub(crate) slab: Bytes,
+	pub(crate) meminfo: HashMap<String, u64>,
 
 	#[cfg(target_os = "macos")]
 	pub(crate) wired: Bytes,
@@ -0,0 +1,13 @@
+//! Periodic sampling of the collectors on a background thread.
+//!
+//! Replaces the usual loop of sleeping, calling each collector and subtracting the previous
+//! values, see `examples/collectors.rs`.
+
+#[allow(clippy::module_inception)]
+mod monitor;
+mod rates;
+mod sample;
+
+pub use monitor::*;
+pub use rates::*;
+pub use sample::*;
@@ -0,0 +1,164 @@
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::monitor::{Sample, Sampler};
+use crate::Result;
+
+/// Returns the number of whole intervals that were skipped if `now` is past `deadline`, and the
+/// deadline of the next tick.
+///
+/// Deadlines are derived from the schedule rather than from the time a sample finished, so the
+/// sampling does not drift when collecting takes a while.
+fn next_deadline(deadline: Instant, now: Instant, interval: Duration) -> (u64, Instant) {
+	let late = now.saturating_duration_since(deadline);
+	let missed = (late.as_nanos() / interval.as_nanos()) as u64;
+	let skip = interval.as_nanos() * (missed as u128 + 1);
+
+	(missed, deadline + Duration::from_nanos(skip as u64))
+}
+
+/// Runs until `deliver` returns `false` or the monitor is stopped.
+fn run<F>(mut sampler: Sampler, interval: Duration, stop: Receiver<()>, mut deliver: F)
+where
+	F: FnMut(Result<Sample>) -> bool,
+{
+	let mut deadline = Instant::now() + interval;
+
+	loop {
+		let wait = deadline.saturating_duration_since(Instant::now());
+		match stop.recv_timeout(wait) {
+			Err(RecvTimeoutError::Timeout) => {}
+			// stopped explicitly or the `Monitor` was dropped
+			Ok(()) | Err(RecvTimeoutError::Disconnected) => return,
+		}
+
+		let (missed_ticks, next) = next_deadline(deadline, Instant::now(), interval);
+		deadline = next;
+
+		let sample = sampler.sample().map(|mut sample| {
+			sample.missed_ticks = missed_ticks;
+			sample
+		});
+		if !deliver(sample) {
+			return;
+		}
+	}
+}
+
+/// Samples cpu, disk and network usage at a fixed interval on a background thread.
+///
+/// The background thread stops when the `Monitor` is dropped.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// let (_monitor, samples) = psutil::monitor::Monitor::channel(Duration::from_secs(1)).unwrap();
+/// let sample = samples.recv().unwrap().unwrap();
+/// println!("{:?}", sample.net_io_rates());
+/// ```
+#[derive(Debug)]
+pub struct Monitor {
+	stop: Option<Sender<()>>,
+	thread: Option<JoinHandle<()>>,
+}
+
+impl Monitor {
+	/// Calls `callback` with a new sample every `interval`.
+	///
+	/// # Panics
+	///
+	/// Panics if `interval` is zero.
+	pub fn spawn<F>(interval: Duration, mut callback: F) -> Result<Monitor>
+	where
+		F: FnMut(Result<Sample>) + Send + 'static,
+	{
+		Monitor::start(interval, move |sample| {
+			callback(sample);
+			true
+		})
+	}
+
+	/// Sends a new sample every `interval` over the returned channel.
+	///
+	/// The background thread also stops once the receiver is dropped.
+	///
+	/// # Panics
+	///
+	/// Panics if `interval` is zero.
+	pub fn channel(interval: Duration) -> Result<(Monitor, Receiver<Result<Sample>>)> {
+		let (sender, receiver) = mpsc::channel();
+		let monitor = Monitor::start(interval, move |sample| sender.send(sample).is_ok())?;
+
+		Ok((monitor, receiver))
+	}
+
+	fn start<F>(interval: Duration, deliver: F) -> Result<Monitor>
+	where
+		F: FnMut(Result<Sample>) -> bool + Send + 'static,
+	{
+		assert!(
+			!interval.is_zero(),
+			"the sampling interval must not be zero"
+		);
+
+		let sampler = Sampler::new()?;
+		let (stop, stopped) = mpsc::channel();
+		let thread = thread::Builder::new()
+			.name("psutil-monitor".into())
+			.spawn(move || run(sampler, interval, stopped, deliver))?;
+
+		Ok(Monitor {
+			stop: Some(stop),
+			thread: Some(thread),
+		})
+	}
+
+	/// Stops sampling and waits for the background thread to exit.
+	pub fn stop(mut self) {
+		self.shutdown();
+	}
+
+	fn shutdown(&mut self) {
+		if let Some(stop) = self.stop.take() {
+			// the thread may have exited already if the receiver was dropped
+			let _ = stop.send(());
+		}
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join();
+		}
+	}
+}
+
+impl Drop for Monitor {
+	fn drop(&mut self) {
+		self.shutdown();
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_next_deadline() {
+		let start = Instant::now();
+		let interval = Duration::from_millis(100);
+
+		let (missed, next) = next_deadline(start, start + Duration::from_millis(5), interval);
+		assert_eq!(missed, 0);
+		assert_eq!(next, start + interval);
+
+		let (missed, next) = next_deadline(start, start + Duration::from_millis(250), interval);
+		assert_eq!(missed, 2);
+		assert_eq!(next, start + Duration::from_millis(300));
+	}
+
+	#[test]
+	fn test_monitor_channel() {
+		let (monitor, samples) = Monitor::channel(Duration::from_millis(10)).unwrap();
+		let sample = samples.recv().unwrap().unwrap();
+		assert!(sample.elapsed() > Duration::default());
+		monitor.stop();
+	}
+}
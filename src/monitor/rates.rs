@@ -0,0 +1,128 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::time::Duration;
+
+use crate::disk::DiskIoCounters;
+use crate::network::NetIoCounters;
+use crate::FloatCount;
+
+fn per_second(value: u64, elapsed: Duration) -> FloatCount {
+	if elapsed.is_zero() {
+		return 0.0;
+	}
+
+	value as FloatCount / elapsed.as_secs_f64()
+}
+
+/// Disk activity per second between two samples.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiskIoRates {
+	pub(crate) read_count: FloatCount,
+	pub(crate) write_count: FloatCount,
+	pub(crate) read_bytes: FloatCount,
+	pub(crate) write_bytes: FloatCount,
+}
+
+impl DiskIoRates {
+	pub(crate) fn new(delta: &DiskIoCounters, elapsed: Duration) -> DiskIoRates {
+		DiskIoRates {
+			read_count: per_second(delta.read_count(), elapsed),
+			write_count: per_second(delta.write_count(), elapsed),
+			read_bytes: per_second(delta.read_bytes(), elapsed),
+			write_bytes: per_second(delta.write_bytes(), elapsed),
+		}
+	}
+
+	/// Reads per second.
+	pub fn read_count(&self) -> FloatCount {
+		self.read_count
+	}
+
+	/// Writes per second.
+	pub fn write_count(&self) -> FloatCount {
+		self.write_count
+	}
+
+	/// Bytes read per second.
+	pub fn read_bytes(&self) -> FloatCount {
+		self.read_bytes
+	}
+
+	/// Bytes written per second.
+	pub fn write_bytes(&self) -> FloatCount {
+		self.write_bytes
+	}
+}
+
+/// Network activity per second between two samples.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetIoRates {
+	pub(crate) bytes_sent: FloatCount,
+	pub(crate) bytes_recv: FloatCount,
+	pub(crate) packets_sent: FloatCount,
+	pub(crate) packets_recv: FloatCount,
+	pub(crate) err_in: FloatCount,
+	pub(crate) err_out: FloatCount,
+	pub(crate) drop_in: FloatCount,
+	pub(crate) drop_out: FloatCount,
+}
+
+impl NetIoRates {
+	pub(crate) fn new(delta: &NetIoCounters, elapsed: Duration) -> NetIoRates {
+		NetIoRates {
+			bytes_sent: per_second(delta.bytes_sent(), elapsed),
+			bytes_recv: per_second(delta.bytes_recv(), elapsed),
+			packets_sent: per_second(delta.packets_sent(), elapsed),
+			packets_recv: per_second(delta.packets_recv(), elapsed),
+			err_in: per_second(delta.err_in(), elapsed),
+			err_out: per_second(delta.err_out(), elapsed),
+			drop_in: per_second(delta.drop_in(), elapsed),
+			drop_out: per_second(delta.drop_out(), elapsed),
+		}
+	}
+
+	/// Bytes sent per second.
+	pub fn bytes_sent(&self) -> FloatCount {
+		self.bytes_sent
+	}
+
+	/// Bytes received per second.
+	pub fn bytes_recv(&self) -> FloatCount {
+		self.bytes_recv
+	}
+
+	/// Packets sent per second.
+	pub fn packets_sent(&self) -> FloatCount {
+		self.packets_sent
+	}
+
+	/// Packets received per second.
+	pub fn packets_recv(&self) -> FloatCount {
+		self.packets_recv
+	}
+
+	/// Errors while receiving per second.
+	pub fn err_in(&self) -> FloatCount {
+		self.err_in
+	}
+
+	/// Errors while sending per second.
+	pub fn err_out(&self) -> FloatCount {
+		self.err_out
+	}
+
+	/// Incoming packets dropped per second.
+	pub fn drop_in(&self) -> FloatCount {
+		self.drop_in
+	}
+
+	/// Outgoing packets dropped per second.
+	pub fn drop_out(&self) -> FloatCount {
+		self.drop_out
+	}
+}
@@ -0,0 +1,217 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::cpu::{CpuTimesPercent, CpuTimesPercentCollector};
+#[cfg(target_os = "linux")]
+use crate::disk::disk_io_counters_per_disk;
+use crate::disk::{DiskIoCounters, DiskIoCountersCollector};
+use crate::monitor::{DiskIoRates, NetIoRates};
+use crate::network::{NetIoCounters, NetIoCountersCollector};
+use crate::Result;
+
+/// Everything measured at one tick of a [`Monitor`](crate::monitor::Monitor).
+///
+/// Deltas and rates cover the time since the previous sample. Devices that appeared since then
+/// only have cumulative counters.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug)]
+pub struct Sample {
+	pub(crate) timestamp: SystemTime,
+	pub(crate) elapsed: Duration,
+	pub(crate) missed_ticks: u64,
+
+	pub(crate) cpu_times_percent: CpuTimesPercent,
+	pub(crate) cpu_times_percent_percpu: Vec<CpuTimesPercent>,
+
+	pub(crate) disk_io_counters_per_partition: HashMap<String, DiskIoCounters>,
+	pub(crate) disk_io_deltas_per_partition: HashMap<String, DiskIoCounters>,
+	pub(crate) disk_io_rates_per_partition: HashMap<String, DiskIoRates>,
+	pub(crate) disk_io_rates: DiskIoRates,
+
+	pub(crate) net_io_counters_pernic: HashMap<String, NetIoCounters>,
+	pub(crate) net_io_deltas_pernic: HashMap<String, NetIoCounters>,
+	pub(crate) net_io_rates_pernic: HashMap<String, NetIoRates>,
+}
+
+impl Sample {
+	/// Wall-clock time the sample was taken at.
+	pub fn timestamp(&self) -> SystemTime {
+		self.timestamp
+	}
+
+	/// Time actually elapsed since the previous sample, which the rates are based on.
+	pub fn elapsed(&self) -> Duration {
+		self.elapsed
+	}
+
+	/// Number of ticks skipped before this sample because sampling fell behind schedule.
+	pub fn missed_ticks(&self) -> u64 {
+		self.missed_ticks
+	}
+
+	pub fn cpu_times_percent(&self) -> &CpuTimesPercent {
+		&self.cpu_times_percent
+	}
+
+	pub fn cpu_times_percent_percpu(&self) -> &[CpuTimesPercent] {
+		&self.cpu_times_percent_percpu
+	}
+
+	/// Overflow-corrected cumulative counters.
+	pub fn disk_io_counters_per_partition(&self) -> &HashMap<String, DiskIoCounters> {
+		&self.disk_io_counters_per_partition
+	}
+
+	pub fn disk_io_deltas_per_partition(&self) -> &HashMap<String, DiskIoCounters> {
+		&self.disk_io_deltas_per_partition
+	}
+
+	pub fn disk_io_rates_per_partition(&self) -> &HashMap<String, DiskIoRates> {
+		&self.disk_io_rates_per_partition
+	}
+
+	/// Rates summed over whole disks.
+	///
+	/// On Linux, partitions as well as device mapper, md and loop devices are left out, since
+	/// their I/O is also counted on the disks below them.
+	pub fn disk_io_rates(&self) -> DiskIoRates {
+		self.disk_io_rates.clone()
+	}
+
+	/// Overflow-corrected cumulative counters.
+	pub fn net_io_counters_pernic(&self) -> &HashMap<String, NetIoCounters> {
+		&self.net_io_counters_pernic
+	}
+
+	pub fn net_io_deltas_pernic(&self) -> &HashMap<String, NetIoCounters> {
+		&self.net_io_deltas_pernic
+	}
+
+	pub fn net_io_rates_pernic(&self) -> &HashMap<String, NetIoRates> {
+		&self.net_io_rates_pernic
+	}
+
+	/// Rates summed over all interfaces.
+	pub fn net_io_rates(&self) -> NetIoRates {
+		let delta = self.net_io_deltas_pernic.values().cloned().sum();
+
+		NetIoRates::new(&delta, self.elapsed)
+	}
+}
+
+fn deltas<T, F>(
+	prev: &HashMap<String, T>,
+	current: &HashMap<String, T>,
+	sub: F,
+) -> HashMap<String, T>
+where
+	F: Fn(&T, &T) -> T,
+{
+	current
+		.iter()
+		.filter_map(|(name, current)| Some((name.clone(), sub(current, prev.get(name)?))))
+		.collect()
+}
+
+/// Owns the collectors and computes the differences between consecutive samples.
+///
+/// Used by [`Monitor`](crate::monitor::Monitor) on its background thread, but can also be driven
+/// manually.
+#[derive(Clone, Debug)]
+pub struct Sampler {
+	cpu_times_percent_collector: CpuTimesPercentCollector,
+	disk_io_counters_collector: DiskIoCountersCollector,
+	net_io_counters_collector: NetIoCountersCollector,
+
+	prev_instant: Instant,
+	prev_disk_io_counters_per_partition: HashMap<String, DiskIoCounters>,
+	prev_net_io_counters_pernic: HashMap<String, NetIoCounters>,
+}
+
+impl Sampler {
+	/// Takes the initial measurements that the first sample is compared against.
+	pub fn new() -> Result<Sampler> {
+		let cpu_times_percent_collector = CpuTimesPercentCollector::new()?;
+		let mut disk_io_counters_collector = DiskIoCountersCollector::default();
+		let mut net_io_counters_collector = NetIoCountersCollector::default();
+
+		let prev_disk_io_counters_per_partition =
+			disk_io_counters_collector.disk_io_counters_per_partition()?;
+		let prev_net_io_counters_pernic = net_io_counters_collector.net_io_counters_pernic()?;
+
+		Ok(Sampler {
+			cpu_times_percent_collector,
+			disk_io_counters_collector,
+			net_io_counters_collector,
+			prev_instant: Instant::now(),
+			prev_disk_io_counters_per_partition,
+			prev_net_io_counters_pernic,
+		})
+	}
+
+	/// Measures everything and compares it against the previous call, or against
+	/// `Sampler::new()` for the first call.
+	pub fn sample(&mut self) -> Result<Sample> {
+		let cpu_times_percent = self.cpu_times_percent_collector.cpu_times_percent()?;
+		let cpu_times_percent_percpu = self
+			.cpu_times_percent_collector
+			.cpu_times_percent_percpu()?;
+		let disk_io_counters_per_partition = self
+			.disk_io_counters_collector
+			.disk_io_counters_per_partition()?;
+		let net_io_counters_pernic = self.net_io_counters_collector.net_io_counters_pernic()?;
+
+		let instant = Instant::now();
+		let elapsed = instant - self.prev_instant;
+
+		let disk_io_deltas_per_partition = deltas(
+			&self.prev_disk_io_counters_per_partition,
+			&disk_io_counters_per_partition,
+			DiskIoCounters::saturating_sub,
+		);
+		let disk_io_rates_per_partition = disk_io_deltas_per_partition
+			.iter()
+			.map(|(name, delta)| (name.clone(), DiskIoRates::new(delta, elapsed)))
+			.collect();
+		#[cfg(target_os = "linux")]
+		let disk_io_delta: DiskIoCounters =
+			disk_io_counters_per_disk(disk_io_deltas_per_partition.clone())?
+				.into_values()
+				.sum();
+		#[cfg(not(target_os = "linux"))]
+		let disk_io_delta: DiskIoCounters = disk_io_deltas_per_partition.values().cloned().sum();
+
+		let net_io_deltas_pernic = deltas(
+			&self.prev_net_io_counters_pernic,
+			&net_io_counters_pernic,
+			NetIoCounters::saturating_sub,
+		);
+		let net_io_rates_pernic = net_io_deltas_pernic
+			.iter()
+			.map(|(name, delta)| (name.clone(), NetIoRates::new(delta, elapsed)))
+			.collect();
+
+		self.prev_instant = instant;
+		self.prev_disk_io_counters_per_partition = disk_io_counters_per_partition.clone();
+		self.prev_net_io_counters_pernic = net_io_counters_pernic.clone();
+
+		Ok(Sample {
+			timestamp: SystemTime::now(),
+			elapsed,
+			missed_ticks: 0,
+			cpu_times_percent,
+			cpu_times_percent_percpu,
+			disk_io_counters_per_partition,
+			disk_io_deltas_per_partition,
+			disk_io_rates_per_partition,
+			disk_io_rates: DiskIoRates::new(&disk_io_delta, elapsed),
+			net_io_counters_pernic,
+			net_io_deltas_pernic,
+			net_io_rates_pernic,
+		})
+	}
+}
@@ -2,6 +2,7 @@ mod net_connection;
 mod net_if_addr;
 mod net_if_stats;
 mod net_io_couters;
+pub mod os;
 mod sys;
 
 pub use net_connection::*;
@@ -20,6 +20,23 @@ pub struct NetIoCounters {
 	pub(crate) err_out: Count,
 	pub(crate) drop_in: Count,
 	pub(crate) drop_out: Count,
+
+	#[cfg(target_os = "linux")]
+	pub(crate) fifo_in: Count,
+	#[cfg(target_os = "linux")]
+	pub(crate) fifo_out: Count,
+	#[cfg(target_os = "linux")]
+	pub(crate) frame_in: Count,
+	#[cfg(target_os = "linux")]
+	pub(crate) compressed_in: Count,
+	#[cfg(target_os = "linux")]
+	pub(crate) compressed_out: Count,
+	#[cfg(target_os = "linux")]
+	pub(crate) multicast_in: Count,
+	#[cfg(target_os = "linux")]
+	pub(crate) collisions: Count,
+	#[cfg(target_os = "linux")]
+	pub(crate) carrier_out: Count,
 }
 
 impl NetIoCounters {
@@ -66,6 +83,38 @@ impl NetIoCounters {
 	pub fn drop_out(&self) -> Count {
 		self.drop_out
 	}
+
+	/// Like `Sub`, but clamps each field at zero instead of overflowing when a counter was reset,
+	/// e.g. because an interface was removed and added again.
+	#[cfg(feature = "monitor")]
+	pub(crate) fn saturating_sub(&self, other: &NetIoCounters) -> NetIoCounters {
+		NetIoCounters {
+			bytes_sent: self.bytes_sent.saturating_sub(other.bytes_sent),
+			bytes_recv: self.bytes_recv.saturating_sub(other.bytes_recv),
+			packets_sent: self.packets_sent.saturating_sub(other.packets_sent),
+			packets_recv: self.packets_recv.saturating_sub(other.packets_recv),
+			err_in: self.err_in.saturating_sub(other.err_in),
+			err_out: self.err_out.saturating_sub(other.err_out),
+			drop_in: self.drop_in.saturating_sub(other.drop_in),
+			drop_out: self.drop_out.saturating_sub(other.drop_out),
+			#[cfg(target_os = "linux")]
+			fifo_in: self.fifo_in.saturating_sub(other.fifo_in),
+			#[cfg(target_os = "linux")]
+			fifo_out: self.fifo_out.saturating_sub(other.fifo_out),
+			#[cfg(target_os = "linux")]
+			frame_in: self.frame_in.saturating_sub(other.frame_in),
+			#[cfg(target_os = "linux")]
+			compressed_in: self.compressed_in.saturating_sub(other.compressed_in),
+			#[cfg(target_os = "linux")]
+			compressed_out: self.compressed_out.saturating_sub(other.compressed_out),
+			#[cfg(target_os = "linux")]
+			multicast_in: self.multicast_in.saturating_sub(other.multicast_in),
+			#[cfg(target_os = "linux")]
+			collisions: self.collisions.saturating_sub(other.collisions),
+			#[cfg(target_os = "linux")]
+			carrier_out: self.carrier_out.saturating_sub(other.carrier_out),
+		}
+	}
 }
 
 fn nowrap(prev: u64, current: u64, corrected: u64) -> u64 {
@@ -98,6 +147,34 @@ fn nowrap_struct(
 		err_out: nowrap(prev.err_out, current.err_out, corrected.err_out),
 		drop_in: nowrap(prev.drop_in, current.drop_in, corrected.drop_in),
 		drop_out: nowrap(prev.drop_out, current.drop_out, corrected.drop_out),
+		#[cfg(target_os = "linux")]
+		fifo_in: nowrap(prev.fifo_in, current.fifo_in, corrected.fifo_in),
+		#[cfg(target_os = "linux")]
+		fifo_out: nowrap(prev.fifo_out, current.fifo_out, corrected.fifo_out),
+		#[cfg(target_os = "linux")]
+		frame_in: nowrap(prev.frame_in, current.frame_in, corrected.frame_in),
+		#[cfg(target_os = "linux")]
+		compressed_in: nowrap(
+			prev.compressed_in,
+			current.compressed_in,
+			corrected.compressed_in,
+		),
+		#[cfg(target_os = "linux")]
+		compressed_out: nowrap(
+			prev.compressed_out,
+			current.compressed_out,
+			corrected.compressed_out,
+		),
+		#[cfg(target_os = "linux")]
+		multicast_in: nowrap(
+			prev.multicast_in,
+			current.multicast_in,
+			corrected.multicast_in,
+		),
+		#[cfg(target_os = "linux")]
+		collisions: nowrap(prev.collisions, current.collisions, corrected.collisions),
+		#[cfg(target_os = "linux")]
+		carrier_out: nowrap(prev.carrier_out, current.carrier_out, corrected.carrier_out),
 	}
 }
 
@@ -0,0 +1,62 @@
+use crate::network::NetIoCounters;
+use crate::Count;
+
+pub trait NetIoCountersExt {
+	/// Number of incoming packets lost because the FIFO buffer of the device overflowed.
+	fn fifo_in(&self) -> Count;
+
+	/// Number of outgoing packets lost because the FIFO buffer of the device underran.
+	fn fifo_out(&self) -> Count;
+
+	/// Number of incoming packets with framing errors, e.g. a misaligned or bad CRC frame.
+	fn frame_in(&self) -> Count;
+
+	/// Number of compressed packets received, only counted by some devices like PPP.
+	fn compressed_in(&self) -> Count;
+
+	/// Number of compressed packets sent, only counted by some devices like PPP.
+	fn compressed_out(&self) -> Count;
+
+	/// Number of multicast packets received.
+	fn multicast_in(&self) -> Count;
+
+	/// Number of collisions on half-duplex links.
+	fn collisions(&self) -> Count;
+
+	/// Number of outgoing packets lost because the link lost carrier, often a bad cable or port.
+	fn carrier_out(&self) -> Count;
+}
+
+impl NetIoCountersExt for NetIoCounters {
+	fn fifo_in(&self) -> Count {
+		self.fifo_in
+	}
+
+	fn fifo_out(&self) -> Count {
+		self.fifo_out
+	}
+
+	fn frame_in(&self) -> Count {
+		self.frame_in
+	}
+
+	fn compressed_in(&self) -> Count {
+		self.compressed_in
+	}
+
+	fn compressed_out(&self) -> Count {
+		self.compressed_out
+	}
+
+	fn multicast_in(&self) -> Count {
+		self.multicast_in
+	}
+
+	fn collisions(&self) -> Count {
+		self.collisions
+	}
+
+	fn carrier_out(&self) -> Count {
+		self.carrier_out
+	}
+}
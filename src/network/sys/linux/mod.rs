@@ -2,9 +2,13 @@ mod net_connections;
 mod net_if_addrs;
 mod net_if_stats;
 mod net_io_counters;
+mod net_protocol_stats;
+mod socket_summary;
 
 pub use net_connections::*;
 pub use net_if_addrs::*;
 pub use net_if_stats::*;
 #[allow(unused_imports)]
 pub use net_io_counters::*;
+pub use net_protocol_stats::*;
+pub use socket_summary::*;
@@ -0,0 +1,759 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::ErrorKind;
+use std::time::{Duration, Instant};
+
+use crate::{read_file, Count, Error, FloatCount, Result};
+
+const PROC_NET_SNMP: &str = "/proc/net/snmp";
+const PROC_NET_SNMP6: &str = "/proc/net/snmp6";
+const PROC_NET_NETSTAT: &str = "/proc/net/netstat";
+
+/// Prefixes of `/proc/net/snmp6`, `Udp6` has to be checked after `UdpLite6`.
+const SNMP6_PREFIXES: [&str; 4] = ["Ip6", "Icmp6", "UdpLite6", "Udp6"];
+
+/// Fields that are current values or settings rather than counters and have no rates.
+const GAUGES: [&str; 7] = [
+	"Ip.Forwarding",
+	"Ip.DefaultTTL",
+	"Tcp.RtoAlgorithm",
+	"Tcp.RtoMin",
+	"Tcp.RtoMax",
+	"Tcp.MaxConn",
+	"Tcp.CurrEstab",
+];
+
+fn parse_value(value: &str, line: &str, path: &str) -> Result<i64> {
+	value.parse().map_err(|err| Error::ParseInt {
+		path: path.into(),
+		contents: line.to_string(),
+		source: err,
+	})
+}
+
+fn insert(counters: &mut HashMap<String, Count>, name: String, value: i64) {
+	// only `Tcp.MaxConn` can be negative, -1 means there is no limit
+	if let Ok(value) = Count::try_from(value) {
+		counters.insert(name, value);
+	}
+}
+
+/// Parses the pairs of header and value lines of `/proc/net/snmp` and `/proc/net/netstat`.
+///
+/// ```text
+/// Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens
+/// Tcp: 1 200 120000 -1 85
+/// ```
+fn parse_snmp(contents: &str, path: &str, counters: &mut HashMap<String, Count>) -> Result<()> {
+	let mut lines = contents.lines();
+
+	while let Some(header) = lines.next() {
+		let values = lines.next().ok_or_else(|| Error::MissingData {
+			path: path.into(),
+			contents: contents.to_string(),
+		})?;
+
+		let mut names = header.split_whitespace();
+		let mut fields = values.split_whitespace();
+		let prefix = match (names.next(), fields.next()) {
+			(Some(prefix), Some(prefix2)) if prefix == prefix2 => prefix.trim_end_matches(':'),
+			_ => {
+				return Err(Error::MissingData {
+					path: path.into(),
+					contents: format!("{}\n{}", header, values),
+				})
+			}
+		};
+
+		for (name, value) in names.zip(fields) {
+			let value = parse_value(value, values, path)?;
+			insert(counters, format!("{}.{}", prefix, name), value);
+		}
+	}
+
+	Ok(())
+}
+
+/// Parses the name and value lines of `/proc/net/snmp6` like `Ip6InReceives 6`.
+fn parse_snmp6(contents: &str, counters: &mut HashMap<String, Count>) -> Result<()> {
+	for line in contents.lines() {
+		let mut fields = line.split_whitespace();
+		let (name, value) = match (fields.next(), fields.next()) {
+			(Some(name), Some(value)) => (name, value),
+			_ => continue,
+		};
+		// per message type counters like `Icmp6InType128` are kept under their full name
+		let name = match SNMP6_PREFIXES
+			.iter()
+			.find_map(|prefix| Some((prefix, name.strip_prefix(prefix)?)))
+		{
+			Some((prefix, rest)) => format!("{}.{}", prefix, rest),
+			None => name.to_string(),
+		};
+
+		insert(counters, name, parse_value(value, line, PROC_NET_SNMP6)?);
+	}
+
+	Ok(())
+}
+
+/// IP counters, from the `Ip` line of `/proc/net/snmp` or the `Ip6` fields of `/proc/net/snmp6`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IpStats {
+	pub(crate) in_receives: Count,
+	pub(crate) in_hdr_errors: Count,
+	pub(crate) in_addr_errors: Count,
+	pub(crate) in_discards: Count,
+	pub(crate) in_delivers: Count,
+	pub(crate) forw_datagrams: Count,
+	pub(crate) out_requests: Count,
+	pub(crate) out_discards: Count,
+	pub(crate) out_no_routes: Count,
+	pub(crate) reasm_fails: Count,
+	pub(crate) frag_fails: Count,
+}
+
+impl IpStats {
+	/// Datagrams received, including those with errors.
+	pub fn in_receives(&self) -> Count {
+		self.in_receives
+	}
+
+	/// Datagrams discarded because of malformed headers.
+	pub fn in_hdr_errors(&self) -> Count {
+		self.in_hdr_errors
+	}
+
+	/// Datagrams discarded because their destination address was invalid for this host.
+	pub fn in_addr_errors(&self) -> Count {
+		self.in_addr_errors
+	}
+
+	/// Datagrams received without errors but discarded, e.g. for lack of buffer space.
+	pub fn in_discards(&self) -> Count {
+		self.in_discards
+	}
+
+	/// Datagrams delivered to the transport protocols.
+	pub fn in_delivers(&self) -> Count {
+		self.in_delivers
+	}
+
+	/// Datagrams forwarded to another host.
+	pub fn forw_datagrams(&self) -> Count {
+		self.forw_datagrams
+	}
+
+	/// Datagrams handed to IP for sending, excluding forwarded ones.
+	pub fn out_requests(&self) -> Count {
+		self.out_requests
+	}
+
+	pub fn out_discards(&self) -> Count {
+		self.out_discards
+	}
+
+	/// Datagrams discarded because there was no route to their destination.
+	pub fn out_no_routes(&self) -> Count {
+		self.out_no_routes
+	}
+
+	/// Failures to reassemble fragmented datagrams.
+	pub fn reasm_fails(&self) -> Count {
+		self.reasm_fails
+	}
+
+	/// Datagrams discarded because they could not be fragmented.
+	pub fn frag_fails(&self) -> Count {
+		self.frag_fails
+	}
+}
+
+/// ICMP counters, from the `Icmp` line of `/proc/net/snmp` or the `Icmp6` fields of
+/// `/proc/net/snmp6`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IcmpStats {
+	pub(crate) in_msgs: Count,
+	pub(crate) in_errors: Count,
+	pub(crate) in_dest_unreachs: Count,
+	pub(crate) out_msgs: Count,
+	pub(crate) out_errors: Count,
+	pub(crate) out_dest_unreachs: Count,
+}
+
+impl IcmpStats {
+	/// Messages received, including those with errors.
+	pub fn in_msgs(&self) -> Count {
+		self.in_msgs
+	}
+
+	/// Messages received with errors, e.g. bad checksums.
+	pub fn in_errors(&self) -> Count {
+		self.in_errors
+	}
+
+	/// Destination unreachable messages received.
+	pub fn in_dest_unreachs(&self) -> Count {
+		self.in_dest_unreachs
+	}
+
+	pub fn out_msgs(&self) -> Count {
+		self.out_msgs
+	}
+
+	/// Messages not sent because of errors within ICMP.
+	pub fn out_errors(&self) -> Count {
+		self.out_errors
+	}
+
+	/// Destination unreachable messages sent.
+	pub fn out_dest_unreachs(&self) -> Count {
+		self.out_dest_unreachs
+	}
+}
+
+/// TCP counters of IPv4 and IPv6 combined, from the `Tcp` line of `/proc/net/snmp` and the
+/// `TcpExt` line of `/proc/net/netstat`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TcpStats {
+	pub(crate) active_opens: Count,
+	pub(crate) passive_opens: Count,
+	pub(crate) attempt_fails: Count,
+	pub(crate) estab_resets: Count,
+	pub(crate) curr_estab: Count,
+	pub(crate) in_segs: Count,
+	pub(crate) out_segs: Count,
+	pub(crate) retrans_segs: Count,
+	pub(crate) in_errs: Count,
+	pub(crate) out_rsts: Count,
+	pub(crate) listen_overflows: Count,
+	pub(crate) listen_drops: Count,
+	pub(crate) syncookies_sent: Count,
+	pub(crate) timeouts: Count,
+	pub(crate) syn_retrans: Option<Count>,
+	pub(crate) abort_on_timeout: Count,
+	pub(crate) abort_on_memory: Count,
+}
+
+impl TcpStats {
+	/// Connections opened by this host, i.e. transitions from `SYN_SENT`.
+	pub fn active_opens(&self) -> Count {
+		self.active_opens
+	}
+
+	/// Connections accepted by this host, i.e. transitions from `LISTEN` to `SYN_RECV`.
+	pub fn passive_opens(&self) -> Count {
+		self.passive_opens
+	}
+
+	/// Connection attempts that failed before being established.
+	pub fn attempt_fails(&self) -> Count {
+		self.attempt_fails
+	}
+
+	/// Established connections that were reset.
+	pub fn estab_resets(&self) -> Count {
+		self.estab_resets
+	}
+
+	/// Connections currently in the `ESTABLISHED` or `CLOSE_WAIT` state.
+	pub fn curr_estab(&self) -> Count {
+		self.curr_estab
+	}
+
+	/// Segments received, including those with errors.
+	pub fn in_segs(&self) -> Count {
+		self.in_segs
+	}
+
+	/// Segments sent, excluding retransmissions.
+	pub fn out_segs(&self) -> Count {
+		self.out_segs
+	}
+
+	/// Segments retransmitted.
+	pub fn retrans_segs(&self) -> Count {
+		self.retrans_segs
+	}
+
+	/// Segments received with errors, e.g. bad checksums.
+	pub fn in_errs(&self) -> Count {
+		self.in_errs
+	}
+
+	/// Segments sent with the `RST` flag.
+	pub fn out_rsts(&self) -> Count {
+		self.out_rsts
+	}
+
+	/// Connections dropped because the accept queue of a listening socket was full.
+	pub fn listen_overflows(&self) -> Count {
+		self.listen_overflows
+	}
+
+	/// Connections dropped by listening sockets for any reason, including overflows.
+	pub fn listen_drops(&self) -> Count {
+		self.listen_drops
+	}
+
+	/// SYN cookies sent because the SYN queue was full.
+	pub fn syncookies_sent(&self) -> Count {
+		self.syncookies_sent
+	}
+
+	/// Retransmission timeouts.
+	pub fn timeouts(&self) -> Count {
+		self.timeouts
+	}
+
+	/// SYN segments retransmitted, `None` before Linux 3.11.
+	pub fn syn_retrans(&self) -> Option<Count> {
+		self.syn_retrans
+	}
+
+	/// Connections aborted after too many retransmissions or keepalive probes.
+	pub fn abort_on_timeout(&self) -> Count {
+		self.abort_on_timeout
+	}
+
+	/// Connections aborted because the system ran out of socket memory.
+	pub fn abort_on_memory(&self) -> Count {
+		self.abort_on_memory
+	}
+}
+
+/// UDP counters, from the `Udp` line of `/proc/net/snmp` or the `Udp6` fields of
+/// `/proc/net/snmp6`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UdpStats {
+	pub(crate) in_datagrams: Count,
+	pub(crate) no_ports: Count,
+	pub(crate) in_errors: Count,
+	pub(crate) out_datagrams: Count,
+	pub(crate) rcvbuf_errors: Count,
+	pub(crate) sndbuf_errors: Count,
+}
+
+impl UdpStats {
+	/// Datagrams delivered to sockets.
+	pub fn in_datagrams(&self) -> Count {
+		self.in_datagrams
+	}
+
+	/// Datagrams received for a port without a socket.
+	pub fn no_ports(&self) -> Count {
+		self.no_ports
+	}
+
+	/// Datagrams that could not be delivered, including receive buffer errors.
+	pub fn in_errors(&self) -> Count {
+		self.in_errors
+	}
+
+	pub fn out_datagrams(&self) -> Count {
+		self.out_datagrams
+	}
+
+	/// Datagrams dropped because the receive buffer of the socket was full.
+	pub fn rcvbuf_errors(&self) -> Count {
+		self.rcvbuf_errors
+	}
+
+	/// Datagrams not sent because the send buffer of the socket was full.
+	pub fn sndbuf_errors(&self) -> Count {
+		self.sndbuf_errors
+	}
+}
+
+/// Network protocol statistics from `/proc/net/snmp`, `/proc/net/snmp6` and
+/// `/proc/net/netstat`, Linux only.
+///
+/// All counters are events since boot in the network namespace of the calling process.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NetProtocolStats {
+	pub(crate) counters: HashMap<String, Count>,
+}
+
+impl NetProtocolStats {
+	/// Every field by its protocol and name, e.g. `Tcp.RetransSegs`, `TcpExt.ListenOverflows` or
+	/// `Ip6.InReceives`.
+	pub fn counters(&self) -> &HashMap<String, Count> {
+		&self.counters
+	}
+
+	pub fn get(&self, name: &str) -> Option<Count> {
+		self.counters.get(name).copied()
+	}
+
+	fn get_or_default(&self, name: &str) -> Count {
+		self.get(name).unwrap_or_default()
+	}
+
+	fn ip_stats(&self, prefix: &str) -> IpStats {
+		let get = |name: &str| self.get_or_default(&format!("{}.{}", prefix, name));
+		// IPv6 counts forwarded datagrams on the way out
+		let forw_datagrams = if prefix == "Ip6" {
+			get("OutForwDatagrams")
+		} else {
+			get("ForwDatagrams")
+		};
+
+		IpStats {
+			in_receives: get("InReceives"),
+			in_hdr_errors: get("InHdrErrors"),
+			in_addr_errors: get("InAddrErrors"),
+			in_discards: get("InDiscards"),
+			in_delivers: get("InDelivers"),
+			forw_datagrams,
+			out_requests: get("OutRequests"),
+			out_discards: get("OutDiscards"),
+			out_no_routes: get("OutNoRoutes"),
+			reasm_fails: get("ReasmFails"),
+			frag_fails: get("FragFails"),
+		}
+	}
+
+	fn icmp_stats(&self, prefix: &str) -> IcmpStats {
+		let get = |name: &str| self.get_or_default(&format!("{}.{}", prefix, name));
+
+		IcmpStats {
+			in_msgs: get("InMsgs"),
+			in_errors: get("InErrors"),
+			in_dest_unreachs: get("InDestUnreachs"),
+			out_msgs: get("OutMsgs"),
+			out_errors: get("OutErrors"),
+			out_dest_unreachs: get("OutDestUnreachs"),
+		}
+	}
+
+	fn udp_stats(&self, prefix: &str) -> UdpStats {
+		let get = |name: &str| self.get_or_default(&format!("{}.{}", prefix, name));
+
+		UdpStats {
+			in_datagrams: get("InDatagrams"),
+			no_ports: get("NoPorts"),
+			in_errors: get("InErrors"),
+			out_datagrams: get("OutDatagrams"),
+			rcvbuf_errors: get("RcvbufErrors"),
+			sndbuf_errors: get("SndbufErrors"),
+		}
+	}
+
+	fn has_ipv6(&self) -> bool {
+		self.counters.contains_key("Ip6.InReceives")
+	}
+
+	pub fn ip(&self) -> IpStats {
+		self.ip_stats("Ip")
+	}
+
+	/// `None` if IPv6 is disabled.
+	pub fn ip6(&self) -> Option<IpStats> {
+		self.has_ipv6().then(|| self.ip_stats("Ip6"))
+	}
+
+	pub fn icmp(&self) -> IcmpStats {
+		self.icmp_stats("Icmp")
+	}
+
+	/// `None` if IPv6 is disabled.
+	pub fn icmp6(&self) -> Option<IcmpStats> {
+		self.has_ipv6().then(|| self.icmp_stats("Icmp6"))
+	}
+
+	pub fn tcp(&self) -> TcpStats {
+		let get = |name: &str| self.get_or_default(name);
+
+		TcpStats {
+			active_opens: get("Tcp.ActiveOpens"),
+			passive_opens: get("Tcp.PassiveOpens"),
+			attempt_fails: get("Tcp.AttemptFails"),
+			estab_resets: get("Tcp.EstabResets"),
+			curr_estab: get("Tcp.CurrEstab"),
+			in_segs: get("Tcp.InSegs"),
+			out_segs: get("Tcp.OutSegs"),
+			retrans_segs: get("Tcp.RetransSegs"),
+			in_errs: get("Tcp.InErrs"),
+			out_rsts: get("Tcp.OutRsts"),
+			listen_overflows: get("TcpExt.ListenOverflows"),
+			listen_drops: get("TcpExt.ListenDrops"),
+			syncookies_sent: get("TcpExt.SyncookiesSent"),
+			timeouts: get("TcpExt.TCPTimeouts"),
+			syn_retrans: self.get("TcpExt.TCPSynRetrans"),
+			abort_on_timeout: get("TcpExt.TCPAbortOnTimeout"),
+			abort_on_memory: get("TcpExt.TCPAbortOnMemory"),
+		}
+	}
+
+	pub fn udp(&self) -> UdpStats {
+		self.udp_stats("Udp")
+	}
+
+	/// `None` if IPv6 is disabled.
+	pub fn udp6(&self) -> Option<UdpStats> {
+		self.has_ipv6().then(|| self.udp_stats("Udp6"))
+	}
+}
+
+/// Reads an optional file, `None` if it doesn't exist.
+pub(crate) fn read_optional(path: &str) -> Result<Option<String>> {
+	match read_file(path) {
+		Ok(contents) => Ok(Some(contents)),
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => Ok(None),
+		Err(err) => Err(err),
+	}
+}
+
+/// `/proc/net/snmp6` is missing if IPv6 is disabled.
+///
+/// New function, not in Python psutil.
+pub fn net_protocol_stats() -> Result<NetProtocolStats> {
+	let mut counters = HashMap::new();
+
+	parse_snmp(&read_file(PROC_NET_SNMP)?, PROC_NET_SNMP, &mut counters)?;
+	if let Some(contents) = read_optional(PROC_NET_NETSTAT)? {
+		parse_snmp(&contents, PROC_NET_NETSTAT, &mut counters)?;
+	}
+	if let Some(contents) = read_optional(PROC_NET_SNMP6)? {
+		parse_snmp6(&contents, &mut counters)?;
+	}
+
+	Ok(NetProtocolStats { counters })
+}
+
+/// Per-second rates of the network protocol counters between two calls of a
+/// [`NetProtocolStatsCollector`].
+///
+/// Fields that are current values or settings, like `Tcp.CurrEstab`, have no rates.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug)]
+pub struct NetProtocolRates {
+	pub(crate) elapsed: Duration,
+	pub(crate) rates: HashMap<String, FloatCount>,
+}
+
+impl NetProtocolRates {
+	fn new(prev: &NetProtocolStats, current: &NetProtocolStats, elapsed: Duration) -> Self {
+		let rates = current
+			.counters
+			.iter()
+			.filter(|(name, _)| !GAUGES.contains(&name.as_str()))
+			.filter_map(|(name, &value)| {
+				// counters are reset when a network namespace is recreated
+				let delta = value.saturating_sub(*prev.counters.get(name)?);
+				let rate = if elapsed.is_zero() {
+					0.0
+				} else {
+					delta as FloatCount / elapsed.as_secs_f64()
+				};
+
+				Some((name.clone(), rate))
+			})
+			.collect();
+
+		NetProtocolRates { elapsed, rates }
+	}
+
+	/// Time between the two calls.
+	pub fn elapsed(&self) -> Duration {
+		self.elapsed
+	}
+
+	/// Rates of every counter by the names of [`NetProtocolStats::counters`].
+	pub fn rates(&self) -> &HashMap<String, FloatCount> {
+		&self.rates
+	}
+
+	pub fn get(&self, name: &str) -> Option<FloatCount> {
+		self.rates.get(name).copied()
+	}
+
+	fn get_or_default(&self, name: &str) -> FloatCount {
+		self.get(name).unwrap_or_default()
+	}
+
+	/// TCP segments retransmitted per second.
+	pub fn tcp_retrans_segs(&self) -> FloatCount {
+		self.get_or_default("Tcp.RetransSegs")
+	}
+
+	/// Share of the sent TCP segments that were retransmissions, between 0 and 1.
+	pub fn tcp_retrans_ratio(&self) -> FloatCount {
+		let retrans_segs = self.tcp_retrans_segs();
+
+		let total = retrans_segs + self.get_or_default("Tcp.OutSegs");
+		if total == 0.0 {
+			0.0
+		} else {
+			retrans_segs / total
+		}
+	}
+
+	/// TCP connections opened by this host per second.
+	pub fn tcp_active_opens(&self) -> FloatCount {
+		self.get_or_default("Tcp.ActiveOpens")
+	}
+
+	/// TCP connections accepted by this host per second.
+	pub fn tcp_passive_opens(&self) -> FloatCount {
+		self.get_or_default("Tcp.PassiveOpens")
+	}
+
+	/// TCP connections dropped per second because an accept queue was full.
+	pub fn tcp_listen_overflows(&self) -> FloatCount {
+		self.get_or_default("TcpExt.ListenOverflows")
+	}
+
+	/// TCP connections dropped per second by listening sockets for any reason.
+	pub fn tcp_listen_drops(&self) -> FloatCount {
+		self.get_or_default("TcpExt.ListenDrops")
+	}
+
+	/// UDP datagrams over IPv4 and IPv6 dropped per second because a receive buffer was full.
+	pub fn udp_rcvbuf_errors(&self) -> FloatCount {
+		self.get_or_default("Udp.RcvbufErrors") + self.get_or_default("Udp6.RcvbufErrors")
+	}
+}
+
+/// Get `NetProtocolRates` in non-blocking mode.
+///
+/// Example:
+///
+/// ```no_run
+/// let mut net_protocol_collector = psutil::network::NetProtocolStatsCollector::new().unwrap();
+///
+/// std::thread::sleep(std::time::Duration::from_secs(1));
+/// let rates = net_protocol_collector.net_protocol_rates().unwrap();
+/// println!("{:.2}% retransmitted", rates.tcp_retrans_ratio() * 100.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct NetProtocolStatsCollector {
+	stats: NetProtocolStats,
+	instant: Instant,
+}
+
+impl NetProtocolStatsCollector {
+	/// Initialize the `NetProtocolStatsCollector` so the method calls are ready to be used.
+	pub fn new() -> Result<NetProtocolStatsCollector> {
+		Ok(NetProtocolStatsCollector {
+			stats: net_protocol_stats()?,
+			instant: Instant::now(),
+		})
+	}
+
+	/// Returns the rates since the last time this was called or since
+	/// `NetProtocolStatsCollector::new()` was called.
+	pub fn net_protocol_rates(&mut self) -> Result<NetProtocolRates> {
+		let current = net_protocol_stats()?;
+		let instant = Instant::now();
+		let rates = NetProtocolRates::new(&self.stats, &current, instant - self.instant);
+
+		self.stats = current;
+		self.instant = instant;
+
+		Ok(rates)
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	fn parse(snmp: &str, netstat: &str, snmp6: &str) -> NetProtocolStats {
+		let mut counters = HashMap::new();
+		parse_snmp(snmp, PROC_NET_SNMP, &mut counters).unwrap();
+		parse_snmp(netstat, PROC_NET_NETSTAT, &mut counters).unwrap();
+		parse_snmp6(snmp6, &mut counters).unwrap();
+
+		NetProtocolStats { counters }
+	}
+
+	#[test]
+	fn test_parse_net_protocol_stats() {
+		let stats = parse(
+			"\
+Ip: Forwarding DefaultTTL InReceives InHdrErrors
+Ip: 2 64 7675 3
+Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens PassiveOpens RetransSegs CurrEstab
+Tcp: 1 200 120000 -1 85 76 12 2
+Udp: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors
+Udp: 74 1 5 74 4
+",
+			"\
+TcpExt: SyncookiesSent ListenOverflows ListenDrops
+TcpExt: 0 7 9
+",
+			"\
+Ip6InReceives                   \t6
+Icmp6InMsgs                     \t2
+Icmp6InType128                  \t1
+Udp6RcvbufErrors                \t3
+UdpLite6RcvbufErrors            \t0
+",
+		);
+
+		assert_eq!(stats.ip().in_receives(), 7675);
+		assert_eq!(stats.ip().in_hdr_errors(), 3);
+		assert_eq!(stats.get("Tcp.MaxConn"), None);
+		assert_eq!(stats.tcp().retrans_segs(), 12);
+		assert_eq!(stats.tcp().curr_estab(), 2);
+		assert_eq!(stats.tcp().listen_overflows(), 7);
+		assert_eq!(stats.tcp().syn_retrans(), None);
+		assert_eq!(stats.udp().rcvbuf_errors(), 4);
+		assert_eq!(stats.ip6().unwrap().in_receives(), 6);
+		assert_eq!(stats.icmp6().unwrap().in_msgs(), 2);
+		assert_eq!(stats.get("Icmp6.InType128"), Some(1));
+		assert_eq!(stats.udp6().unwrap().rcvbuf_errors(), 3);
+		assert_eq!(stats.get("UdpLite6.RcvbufErrors"), Some(0));
+
+		let ipv4_only = parse("Ip: InReceives\nIp: 1\n", "", "");
+		assert_eq!(ipv4_only.ip6(), None);
+
+		let mut counters = HashMap::new();
+		assert!(parse_snmp("Ip: InReceives\n", PROC_NET_SNMP, &mut counters).is_err());
+	}
+
+	#[test]
+	fn test_net_protocol_rates() {
+		let prev = parse(
+			"Tcp: CurrEstab OutSegs RetransSegs\nTcp: 10 900 0\n",
+			"",
+			"Udp6RcvbufErrors 1\n",
+		);
+		let current = parse(
+			"Tcp: CurrEstab OutSegs RetransSegs\nTcp: 20 1880 20\nUdp: RcvbufErrors\nUdp: 4\n",
+			"",
+			"Udp6RcvbufErrors 5\n",
+		);
+		let rates = NetProtocolRates::new(&prev, &current, Duration::from_secs(2));
+
+		assert_eq!(rates.get("Tcp.CurrEstab"), None);
+		assert_eq!(rates.tcp_retrans_segs(), 10.0);
+		assert_eq!(rates.tcp_retrans_ratio(), 0.02);
+		// `Udp.RcvbufErrors` is missing from the first sample
+		assert_eq!(rates.udp_rcvbuf_errors(), 2.0);
+	}
+
+	#[test]
+	fn test_net_protocol_stats() {
+		let stats = net_protocol_stats().unwrap();
+
+		assert!(stats.ip().in_receives() >= stats.ip().in_delivers());
+	}
+}
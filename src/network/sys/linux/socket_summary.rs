@@ -0,0 +1,340 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, ErrorKind};
+
+use crate::common::TcpConnectionStatus;
+use crate::network::read_optional;
+use crate::{read_file, Bytes, Count, Error, Result, PAGE_SIZE};
+
+const PROC_NET_SOCKSTAT: &str = "/proc/net/sockstat";
+const PROC_NET_SOCKSTAT6: &str = "/proc/net/sockstat6";
+const PROC_NET_TCP: &str = "/proc/net/tcp";
+const PROC_NET_TCP6: &str = "/proc/net/tcp6";
+
+/// Sockets of one protocol from `/proc/net/sockstat` or `/proc/net/sockstat6`.
+///
+/// Only TCP sockets have all fields, the IPv6 protocols only have `in_use`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SocketStats {
+	pub(crate) in_use: Count,
+	pub(crate) orphan: Option<Count>,
+	pub(crate) time_wait: Option<Count>,
+	pub(crate) alloc: Option<Count>,
+	pub(crate) memory: Option<Bytes>,
+}
+
+impl SocketStats {
+	/// Sockets in use, excluding TCP sockets in `TIME_WAIT`.
+	pub fn in_use(&self) -> Count {
+		self.in_use
+	}
+
+	/// TCP sockets no longer attached to a file descriptor but not closed yet.
+	pub fn orphan(&self) -> Option<Count> {
+		self.orphan
+	}
+
+	/// TCP sockets in `TIME_WAIT`.
+	pub fn time_wait(&self) -> Option<Count> {
+		self.time_wait
+	}
+
+	/// TCP sockets allocated, including orphans but excluding those in `TIME_WAIT`.
+	pub fn alloc(&self) -> Option<Count> {
+		self.alloc
+	}
+
+	/// Memory used by the buffers of the sockets, or by fragments waiting for reassembly.
+	pub fn memory(&self) -> Option<Bytes> {
+		self.memory
+	}
+}
+
+/// Socket counts from `/proc/net/sockstat` and `/proc/net/sockstat6`, Linux only.
+///
+/// Unlike listing connections, reading these costs the same regardless of the number of sockets.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SocketSummary {
+	pub(crate) sockets_used: Count,
+	pub(crate) protocols: HashMap<String, SocketStats>,
+}
+
+impl SocketSummary {
+	/// Sockets of all families in use, including Unix domain sockets.
+	pub fn sockets_used(&self) -> Count {
+		self.sockets_used
+	}
+
+	/// Every protocol by its name, e.g. `TCP`, `UDP6` or `FRAG`.
+	pub fn protocols(&self) -> &HashMap<String, SocketStats> {
+		&self.protocols
+	}
+
+	pub fn get(&self, protocol: &str) -> Option<&SocketStats> {
+		self.protocols.get(protocol)
+	}
+
+	fn get_or_default(&self, protocol: &str) -> SocketStats {
+		self.get(protocol).cloned().unwrap_or_default()
+	}
+
+	/// TCP sockets over IPv4 and IPv6, only `in_use` is split by family.
+	pub fn tcp(&self) -> SocketStats {
+		self.get_or_default("TCP")
+	}
+
+	/// `None` if IPv6 is disabled.
+	pub fn tcp6(&self) -> Option<SocketStats> {
+		self.get("TCP6").cloned()
+	}
+
+	/// UDP sockets, `memory` includes IPv6.
+	pub fn udp(&self) -> SocketStats {
+		self.get_or_default("UDP")
+	}
+
+	/// `None` if IPv6 is disabled.
+	pub fn udp6(&self) -> Option<SocketStats> {
+		self.get("UDP6").cloned()
+	}
+
+	pub fn raw(&self) -> SocketStats {
+		self.get_or_default("RAW")
+	}
+
+	/// `None` if IPv6 is disabled.
+	pub fn raw6(&self) -> Option<SocketStats> {
+		self.get("RAW6").cloned()
+	}
+}
+
+/// Parses lines like `TCP: inuse 4 orphan 0 tw 4 alloc 4 mem 0`.
+fn parse_sockstat(contents: &str, path: &str, summary: &mut SocketSummary) -> Result<()> {
+	for line in contents.lines() {
+		let (protocol, rest) = match line.split_once(':') {
+			Some(field) => field,
+			None => continue,
+		};
+
+		let mut values = HashMap::new();
+		let mut fields = rest.split_whitespace();
+		while let (Some(name), Some(value)) = (fields.next(), fields.next()) {
+			let value: u64 = value.parse().map_err(|err| Error::ParseInt {
+				path: path.into(),
+				contents: line.to_string(),
+				source: err,
+			})?;
+			values.insert(name, value);
+		}
+
+		if protocol == "sockets" {
+			summary.sockets_used = values.get("used").copied().unwrap_or_default();
+			continue;
+		}
+
+		summary.protocols.insert(
+			protocol.to_string(),
+			SocketStats {
+				in_use: values.get("inuse").copied().unwrap_or_default(),
+				orphan: values.get("orphan").copied(),
+				time_wait: values.get("tw").copied(),
+				alloc: values.get("alloc").copied(),
+				// `mem` is in pages, `memory` of fragments is in bytes
+				memory: values
+					.get("mem")
+					.map(|pages| pages * *PAGE_SIZE)
+					.or_else(|| values.get("memory").copied()),
+			},
+		);
+	}
+
+	Ok(())
+}
+
+/// `/proc/net/sockstat6` is missing if IPv6 is disabled.
+///
+/// New function, not in Python psutil.
+pub fn socket_summary() -> Result<SocketSummary> {
+	let mut summary = SocketSummary::default();
+
+	parse_sockstat(
+		&read_file(PROC_NET_SOCKSTAT)?,
+		PROC_NET_SOCKSTAT,
+		&mut summary,
+	)?;
+	if let Some(contents) = read_optional(PROC_NET_SOCKSTAT6)? {
+		parse_sockstat(&contents, PROC_NET_SOCKSTAT6, &mut summary)?;
+	}
+
+	Ok(summary)
+}
+
+/// Maps the hexadecimal `st` field of `/proc/net/tcp` to a status.
+fn parse_tcp_state(state: &str) -> Option<TcpConnectionStatus> {
+	let status = match state {
+		"01" => TcpConnectionStatus::Established,
+		"02" => TcpConnectionStatus::SynSent,
+		// request sockets in `NEW_SYN_RECV` are reported as `03` as well
+		"03" => TcpConnectionStatus::SynRecv,
+		"04" => TcpConnectionStatus::FinWait1,
+		"05" => TcpConnectionStatus::FinWait2,
+		"06" => TcpConnectionStatus::TimeWait,
+		"07" => TcpConnectionStatus::Close,
+		"08" => TcpConnectionStatus::CloseWait,
+		"09" => TcpConnectionStatus::LastAck,
+		"0A" => TcpConnectionStatus::Listen,
+		"0B" => TcpConnectionStatus::Closing,
+		_ => return None,
+	};
+
+	Some(status)
+}
+
+fn count_tcp_states<R: BufRead>(
+	mut reader: R,
+	path: &str,
+	counts: &mut HashMap<TcpConnectionStatus, Count>,
+) -> Result<()> {
+	let mut line = String::new();
+	let mut header = true;
+
+	loop {
+		line.clear();
+		let read = reader.read_line(&mut line).map_err(|err| Error::ReadFile {
+			path: path.into(),
+			source: err,
+		})?;
+		if read == 0 {
+			return Ok(());
+		}
+		if header {
+			header = false;
+			continue;
+		}
+
+		// e.g. `0: 0100007F:BC8F 00000000:0000 0A ...`
+		let status = line.split_whitespace().nth(3).and_then(parse_tcp_state);
+		match status {
+			Some(status) => *counts.entry(status).or_default() += 1,
+			None => {
+				return Err(Error::MissingData {
+					path: path.into(),
+					contents: line.clone(),
+				})
+			}
+		}
+	}
+}
+
+/// Number of TCP sockets over IPv4 and IPv6 in each status, Linux only.
+///
+/// This reads the status of each socket from `/proc/net/tcp` and `/proc/net/tcp6`, so the cost
+/// still grows with the number of sockets: the kernel formats a line for every one of them. It
+/// only skips resolving addresses and owning processes, which listing the connections does.
+/// [`socket_summary`] is constant time if the totals are enough. Statuses without sockets are
+/// missing from the result.
+///
+/// New function, not in Python psutil.
+pub fn tcp_status_counts() -> Result<HashMap<TcpConnectionStatus, Count>> {
+	let mut counts = HashMap::new();
+
+	for path in &[PROC_NET_TCP, PROC_NET_TCP6] {
+		let file = match File::open(path) {
+			Ok(file) => file,
+			// IPv6 is disabled
+			Err(err) if err.kind() == ErrorKind::NotFound && *path == PROC_NET_TCP6 => continue,
+			Err(err) => {
+				return Err(Error::ReadFile {
+					path: path.into(),
+					source: err,
+				})
+			}
+		};
+
+		count_tcp_states(BufReader::new(file), path, &mut counts)?;
+	}
+
+	Ok(counts)
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_sockstat() {
+		let mut summary = SocketSummary::default();
+		parse_sockstat(
+			"\
+sockets: used 18
+TCP: inuse 4 orphan 1 tw 5 alloc 6 mem 2
+UDP: inuse 3 mem 1
+UDPLITE: inuse 0
+RAW: inuse 0
+FRAG: inuse 1 memory 4096
+",
+			PROC_NET_SOCKSTAT,
+			&mut summary,
+		)
+		.unwrap();
+		assert_eq!(summary.tcp6(), None);
+
+		parse_sockstat(
+			"TCP6: inuse 2\nUDP6: inuse 1\nRAW6: inuse 0\nFRAG6: inuse 0 memory 0\n",
+			PROC_NET_SOCKSTAT6,
+			&mut summary,
+		)
+		.unwrap();
+
+		assert_eq!(summary.sockets_used(), 18);
+		let tcp = summary.tcp();
+		assert_eq!(tcp.in_use(), 4);
+		assert_eq!(tcp.orphan(), Some(1));
+		assert_eq!(tcp.time_wait(), Some(5));
+		assert_eq!(tcp.alloc(), Some(6));
+		assert_eq!(tcp.memory(), Some(2 * *PAGE_SIZE));
+		assert_eq!(summary.udp().memory(), Some(*PAGE_SIZE));
+		assert_eq!(summary.get("FRAG").unwrap().memory(), Some(4096));
+		assert_eq!(summary.tcp6().unwrap().in_use(), 2);
+		assert_eq!(summary.tcp6().unwrap().orphan(), None);
+	}
+
+	#[test]
+	fn test_count_tcp_states() {
+		let contents = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:BC8F 00000000:0000 0A 00000000:00000000 00:00000000 00000000 65534        0 928 1
+   1: 00000000:07E8 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 662 1
+   2: 0100007F:07E8 0100007F:D2C4 01 00000000:00000000 00:00000000 00000000     0        0 701 1
+   3: 0100007F:07E8 0100007F:D2C6 06 00000000:00000000 03:00000F9A 00000000     0        0 0 3
+";
+		let mut counts = HashMap::new();
+		count_tcp_states(contents.as_bytes(), PROC_NET_TCP, &mut counts).unwrap();
+
+		assert_eq!(counts.len(), 3);
+		assert_eq!(counts[&TcpConnectionStatus::Listen], 2);
+		assert_eq!(counts[&TcpConnectionStatus::Established], 1);
+		assert_eq!(counts[&TcpConnectionStatus::TimeWait], 1);
+
+		assert!(
+			count_tcp_states("header\n0: garbage\n".as_bytes(), PROC_NET_TCP, &mut counts).is_err()
+		);
+	}
+
+	#[test]
+	fn test_socket_summary() {
+		let summary = socket_summary().unwrap();
+
+		assert!(summary.sockets_used() > 0);
+		tcp_status_counts().unwrap();
+	}
+}
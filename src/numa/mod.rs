@@ -0,0 +1,12 @@
+//! NUMA topology and per-node memory statistics, Linux only.
+//!
+//! See [`ProcessExt::numa_maps`](crate::process::os::linux::ProcessExt::numa_maps) for the
+//! placement of a single process.
+
+cfg_if::cfg_if! {
+	if #[cfg(target_os = "linux")] {
+		mod node;
+
+		pub use node::*;
+	}
+}
@@ -0,0 +1,309 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::{read_dir, read_file, Bytes, Count, Error, Result};
+
+const SYS_NODE: &str = "/sys/devices/system/node";
+const SYS_NODE_ONLINE: &str = "/sys/devices/system/node/online";
+
+/// Allocation counters of a node from `numastat`, in pages.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NumaStat {
+	pub(crate) numa_hit: Count,
+	pub(crate) numa_miss: Count,
+	pub(crate) numa_foreign: Count,
+	pub(crate) interleave_hit: Count,
+	pub(crate) local_node: Count,
+	pub(crate) other_node: Count,
+}
+
+impl NumaStat {
+	/// Pages allocated on this node as intended.
+	pub fn numa_hit(&self) -> Count {
+		self.numa_hit
+	}
+
+	/// Pages allocated on this node although another node was preferred.
+	pub fn numa_miss(&self) -> Count {
+		self.numa_miss
+	}
+
+	/// Pages intended for this node but allocated on another one.
+	pub fn numa_foreign(&self) -> Count {
+		self.numa_foreign
+	}
+
+	/// Interleaved pages allocated on this node as intended.
+	pub fn interleave_hit(&self) -> Count {
+		self.interleave_hit
+	}
+
+	/// Pages allocated on this node while the process ran on it.
+	pub fn local_node(&self) -> Count {
+		self.local_node
+	}
+
+	/// Pages allocated on this node while the process ran on another one.
+	pub fn other_node(&self) -> Count {
+		self.other_node
+	}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug)]
+pub struct NumaNode {
+	pub(crate) id: u32,
+	pub(crate) cpus: Vec<u32>,
+	pub(crate) distances: BTreeMap<u32, u32>,
+	pub(crate) meminfo: HashMap<String, u64>,
+	pub(crate) numastat: NumaStat,
+}
+
+impl NumaNode {
+	pub fn id(&self) -> u32 {
+		self.id
+	}
+
+	/// Cpus belonging to the node.
+	pub fn cpus(&self) -> &[u32] {
+		&self.cpus
+	}
+
+	/// Relative access cost from this node to every online node, by node id. 10 is local.
+	pub fn distances(&self) -> &BTreeMap<u32, u32> {
+		&self.distances
+	}
+
+	pub fn total(&self) -> Bytes {
+		self.meminfo.get("MemTotal").copied().unwrap_or_default()
+	}
+
+	pub fn free(&self) -> Bytes {
+		self.meminfo.get("MemFree").copied().unwrap_or_default()
+	}
+
+	pub fn used(&self) -> Bytes {
+		self.meminfo.get("MemUsed").copied().unwrap_or_default()
+	}
+
+	/// Every field of the node's `meminfo`, with the same units as
+	/// [`VirtualMemoryExt::meminfo`](crate::memory::os::linux::VirtualMemoryExt::meminfo).
+	pub fn meminfo(&self) -> &HashMap<String, u64> {
+		&self.meminfo
+	}
+
+	pub fn numastat(&self) -> &NumaStat {
+		&self.numastat
+	}
+}
+
+/// Parses cpu or node lists such as `0-3,8,10-11`.
+pub(crate) fn parse_list(contents: &str, path: &Path) -> Result<Vec<u32>> {
+	let parse = |s: &str| -> Result<u32> {
+		s.parse().map_err(|err| Error::ParseInt {
+			path: path.into(),
+			contents: contents.to_string(),
+			source: err,
+		})
+	};
+
+	let mut list = Vec::new();
+	for range in contents.trim().split(',').filter(|range| !range.is_empty()) {
+		match range.split_once('-') {
+			Some((start, end)) => list.extend(parse(start)?..=parse(end)?),
+			None => list.push(parse(range)?),
+		}
+	}
+
+	Ok(list)
+}
+
+/// Parses lines like `Node 0 MemTotal:  6147400 kB`.
+fn parse_meminfo(contents: &str, path: &Path) -> Result<HashMap<String, u64>> {
+	contents
+		.lines()
+		.filter(|line| !line.trim().is_empty())
+		.map(|line| {
+			let fields: Vec<&str> = line.split_whitespace().collect();
+			if fields.len() < 4 {
+				return Err(Error::MissingData {
+					path: path.into(),
+					contents: line.to_string(),
+				});
+			}
+
+			let mut value: u64 = fields[3].parse().map_err(|err| Error::ParseInt {
+				path: path.into(),
+				contents: line.to_string(),
+				source: err,
+			})?;
+			if fields.get(4) == Some(&"kB") {
+				value *= 1024;
+			}
+
+			Ok((fields[2].trim_end_matches(':').to_string(), value))
+		})
+		.collect()
+}
+
+fn parse_numastat(contents: &str, path: &Path) -> Result<NumaStat> {
+	let mut numastat = NumaStat::default();
+
+	for line in contents.lines() {
+		let (name, value) = match line.split_once(' ') {
+			Some(field) => field,
+			None => continue,
+		};
+		let value = value.trim().parse().map_err(|err| Error::ParseInt {
+			path: path.into(),
+			contents: line.to_string(),
+			source: err,
+		})?;
+
+		match name {
+			"numa_hit" => numastat.numa_hit = value,
+			"numa_miss" => numastat.numa_miss = value,
+			"numa_foreign" => numastat.numa_foreign = value,
+			"interleave_hit" => numastat.interleave_hit = value,
+			"local_node" => numastat.local_node = value,
+			"other_node" => numastat.other_node = value,
+			_ => {}
+		}
+	}
+
+	Ok(numastat)
+}
+
+/// Pairs the fields of `distance` with the online node ids, which aren't necessarily contiguous.
+fn parse_distances(contents: &str, online: &[u32], path: &Path) -> Result<BTreeMap<u32, u32>> {
+	let distances = contents
+		.split_whitespace()
+		.map(|field| {
+			field.parse().map_err(|err| Error::ParseInt {
+				path: path.into(),
+				contents: contents.to_string(),
+				source: err,
+			})
+		})
+		.collect::<Result<Vec<u32>>>()?;
+
+	if distances.len() != online.len() {
+		return Err(Error::MissingData {
+			path: path.into(),
+			contents: contents.to_string(),
+		});
+	}
+
+	Ok(online.iter().copied().zip(distances).collect())
+}
+
+fn read_node(id: u32, online: &[u32], path: &Path) -> Result<NumaNode> {
+	let read = |name: &str| -> Result<(String, PathBuf)> {
+		let file = path.join(name);
+		Ok((read_file(&file)?, file))
+	};
+
+	let (cpulist, cpulist_path) = read("cpulist")?;
+	let (distance, distance_path) = read("distance")?;
+	let (meminfo, meminfo_path) = read("meminfo")?;
+	let (numastat, numastat_path) = read("numastat")?;
+
+	Ok(NumaNode {
+		id,
+		cpus: parse_list(&cpulist, &cpulist_path)?,
+		distances: parse_distances(&distance, online, &distance_path)?,
+		meminfo: parse_meminfo(&meminfo, &meminfo_path)?,
+		numastat: parse_numastat(&numastat, &numastat_path)?,
+	})
+}
+
+/// Online NUMA nodes ordered by id.
+///
+/// Empty if the kernel was built without NUMA support. Machines without multiple nodes still
+/// have node 0.
+pub fn numa_nodes() -> Result<Vec<NumaNode>> {
+	let entries = match read_dir(SYS_NODE) {
+		Ok(entries) => entries,
+		Err(Error::ReadFile { source, .. }) if source.kind() == ErrorKind::NotFound => {
+			return Ok(Vec::new());
+		}
+		Err(err) => return Err(err),
+	};
+	let online = parse_list(&read_file(SYS_NODE_ONLINE)?, Path::new(SYS_NODE_ONLINE))?;
+
+	let mut nodes = entries
+		.iter()
+		.filter_map(|entry| {
+			let id = entry
+				.file_name()
+				.to_str()?
+				.strip_prefix("node")?
+				.parse()
+				.ok()?;
+
+			Some(read_node(id, &online, &entry.path()))
+		})
+		.collect::<Result<Vec<_>>>()?;
+	nodes.sort_by_key(|node| node.id);
+
+	Ok(nodes)
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_list() {
+		let path = Path::new("cpulist");
+
+		assert_eq!(
+			parse_list("0-3,8,10-11\n", path).unwrap(),
+			[0, 1, 2, 3, 8, 10, 11]
+		);
+		assert_eq!(parse_list("0\n", path).unwrap(), [0]);
+		// memory-only nodes have no cpus
+		assert!(parse_list("\n", path).unwrap().is_empty());
+		assert!(parse_list("0-a\n", path).is_err());
+	}
+
+	#[test]
+	fn test_parse_node_files() {
+		let meminfo = parse_meminfo(
+			"Node 1 MemTotal:       65536 kB\nNode 1 MemFree:        1024 kB\nNode 1 HugePages_Total:     2\n",
+			Path::new("meminfo"),
+		)
+		.unwrap();
+		assert_eq!(meminfo["MemTotal"], 65536 * 1024);
+		assert_eq!(meminfo["HugePages_Total"], 2);
+
+		let numastat = parse_numastat(
+			"numa_hit 100\nnuma_miss 5\nnuma_foreign 7\ninterleave_hit 1\nlocal_node 90\nother_node 15\n",
+			Path::new("numastat"),
+		)
+		.unwrap();
+		assert_eq!(numastat.numa_miss(), 5);
+		assert_eq!(numastat.other_node(), 15);
+
+		let path = Path::new("distance");
+		let distances = parse_distances("10 21 21\n", &[0, 2, 3], path).unwrap();
+		assert_eq!(distances[&0], 10);
+		assert_eq!(distances[&3], 21);
+		assert_eq!(distances.get(&1), None);
+		assert!(parse_distances("10 21\n", &[0, 2, 3], path).is_err());
+	}
+
+	#[test]
+	fn test_numa_nodes() {
+		for node in numa_nodes().unwrap() {
+			assert!(node.total() >= node.free());
+		}
+	}
+}
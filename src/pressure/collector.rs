@@ -0,0 +1,166 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::pressure::{cgroup_pressure_path, pressure_path, read_pressure, Pressure, Resource};
+use crate::{Percent, Result};
+
+/// Stall time accumulated between two calls of a [`PressureCollector`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PressureDelta {
+	pub(crate) some: Duration,
+	pub(crate) full: Option<Duration>,
+	pub(crate) elapsed: Duration,
+}
+
+impl PressureDelta {
+	/// Time in which at least some tasks were stalled.
+	pub fn some(&self) -> Duration {
+		self.some
+	}
+
+	/// Time in which all non-idle tasks were stalled.
+	pub fn full(&self) -> Option<Duration> {
+		self.full
+	}
+
+	/// Wall-clock time between the two calls.
+	pub fn elapsed(&self) -> Duration {
+		self.elapsed
+	}
+
+	/// `some` as a percentage of `elapsed`.
+	pub fn some_percent(&self) -> Percent {
+		percent(self.some, self.elapsed)
+	}
+
+	/// `full` as a percentage of `elapsed`.
+	pub fn full_percent(&self) -> Option<Percent> {
+		self.full.map(|full| percent(full, self.elapsed))
+	}
+}
+
+fn percent(stalled: Duration, elapsed: Duration) -> Percent {
+	if elapsed.is_zero() {
+		return 0.0;
+	}
+
+	((stalled.as_secs_f64() / elapsed.as_secs_f64()) * 100.0) as Percent
+}
+
+/// Get stall time deltas, exact over any interval unlike the kernel's fixed running averages.
+///
+/// The first call for a resource or cgroup returns zero deltas.
+///
+/// Example:
+///
+/// ```no_run
+/// use psutil::pressure::{PressureCollector, Resource};
+///
+/// let mut pressure_collector = PressureCollector::default();
+///
+/// pressure_collector.pressure(Resource::Memory).unwrap();
+/// std::thread::sleep(std::time::Duration::from_secs(1));
+/// let delta = pressure_collector.pressure(Resource::Memory).unwrap();
+/// println!("{}% of the time some tasks waited for memory", delta.some_percent());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PressureCollector {
+	prev: HashMap<PathBuf, (Instant, Pressure)>,
+}
+
+impl PressureCollector {
+	/// System-wide stall time since the last call for `resource`.
+	pub fn pressure(&mut self, resource: Resource) -> Result<PressureDelta> {
+		self.delta(pressure_path(resource))
+	}
+
+	/// Stall time of a cgroup v2 directory since the last call for it and `resource`.
+	pub fn cgroup_pressure<P>(&mut self, cgroup: P, resource: Resource) -> Result<PressureDelta>
+	where
+		P: AsRef<Path>,
+	{
+		self.delta(cgroup_pressure_path(cgroup, resource))
+	}
+
+	fn delta(&mut self, path: PathBuf) -> Result<PressureDelta> {
+		let current = read_pressure(&path)?;
+
+		Ok(self.update(path, current, Instant::now()))
+	}
+
+	/// Compares `current` against the previous snapshot of `path` and replaces it.
+	fn update(&mut self, path: PathBuf, current: Pressure, now: Instant) -> PressureDelta {
+		let delta = match self.prev.get(&path) {
+			Some((prev_instant, prev)) => PressureDelta {
+				// the totals of a recreated cgroup start over, which gives a delta of zero for
+				// that interval rather than the stall time of the new cgroup
+				some: current.some.total.saturating_sub(prev.some.total),
+				full: match (&current.full, &prev.full) {
+					(Some(current), Some(prev)) => Some(current.total.saturating_sub(prev.total)),
+					_ => None,
+				},
+				elapsed: now - *prev_instant,
+			},
+			None => PressureDelta {
+				full: current.full.as_ref().map(|_| Duration::default()),
+				..PressureDelta::default()
+			},
+		};
+
+		self.prev.insert(path, (now, current));
+
+		delta
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use crate::pressure::parse_pressure;
+
+	#[test]
+	fn test_pressure_collector() {
+		let path = PathBuf::from("/proc/pressure/memory");
+		let snapshot = |some: u64, full: u64| {
+			let contents = format!(
+				"some avg10=0.00 avg60=0.00 avg300=0.00 total={}\n\
+				 full avg10=0.00 avg60=0.00 avg300=0.00 total={}\n",
+				some, full
+			);
+			parse_pressure(&contents, &path).unwrap()
+		};
+
+		let mut collector = PressureCollector::default();
+		let start = Instant::now();
+
+		let first = collector.update(path.clone(), snapshot(1_000_000, 400_000), start);
+		assert_eq!(first.some(), Duration::default());
+		assert_eq!(first.full(), Some(Duration::default()));
+
+		let second = collector.update(
+			path.clone(),
+			snapshot(1_500_000, 600_000),
+			start + Duration::from_secs(2),
+		);
+		assert_eq!(second.elapsed(), Duration::from_secs(2));
+		assert_eq!(second.some(), Duration::from_millis(500));
+		assert_eq!(second.full(), Some(Duration::from_millis(200)));
+		assert_eq!(second.some_percent(), 25.0);
+		assert_eq!(second.full_percent(), Some(10.0));
+
+		// the cgroup was recreated and its totals started over
+		let third = collector.update(
+			path.clone(),
+			snapshot(100, 0),
+			start + Duration::from_secs(3),
+		);
+		assert_eq!(third.some(), Duration::default());
+		assert_eq!(third.full(), Some(Duration::default()));
+	}
+}
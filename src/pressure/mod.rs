@@ -0,0 +1,15 @@
+//! Pressure Stall Information (PSI), Linux only.
+//!
+//! Reports how much time tasks spent waiting for cpu, memory or io, system-wide or per cgroup.
+//! Requires Linux 4.20, built with `CONFIG_PSI` and not booted with `psi=0`.
+
+cfg_if::cfg_if! {
+	if #[cfg(target_os = "linux")] {
+		mod collector;
+		#[allow(clippy::module_inception)]
+		mod pressure;
+
+		pub use collector::*;
+		pub use pressure::*;
+	}
+}
@@ -0,0 +1,208 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::{read_file, Error, Percent, Result};
+
+const PROC_PRESSURE: &str = "/proc/pressure";
+
+/// A resource that tasks can stall on.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Resource {
+	Cpu,
+	Memory,
+	Io,
+}
+
+impl Resource {
+	/// Name of the resource as used in the file names.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Resource::Cpu => "cpu",
+			Resource::Memory => "memory",
+			Resource::Io => "io",
+		}
+	}
+}
+
+/// One line of a pressure file.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PressureStats {
+	pub(crate) avg10: Percent,
+	pub(crate) avg60: Percent,
+	pub(crate) avg300: Percent,
+	pub(crate) total: Duration,
+}
+
+impl PressureStats {
+	/// Percentage of time stalled, averaged over the last 10 seconds.
+	pub fn avg10(&self) -> Percent {
+		self.avg10
+	}
+
+	/// Percentage of time stalled, averaged over the last 60 seconds.
+	pub fn avg60(&self) -> Percent {
+		self.avg60
+	}
+
+	/// Percentage of time stalled, averaged over the last 300 seconds.
+	pub fn avg300(&self) -> Percent {
+		self.avg300
+	}
+
+	/// Total time stalled.
+	pub fn total(&self) -> Duration {
+		self.total
+	}
+}
+
+/// Pressure Stall Information of a resource.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Pressure {
+	pub(crate) some: PressureStats,
+	pub(crate) full: Option<PressureStats>,
+}
+
+impl Pressure {
+	/// Time in which at least some tasks were stalled on the resource.
+	pub fn some(&self) -> &PressureStats {
+		&self.some
+	}
+
+	/// Time in which all non-idle tasks were stalled on the resource at the same time.
+	///
+	/// `None` for cpu pressure before Linux 5.13. System-wide cpu pressure always reports zero.
+	pub fn full(&self) -> Option<&PressureStats> {
+		self.full.as_ref()
+	}
+}
+
+fn parse_stats(line: &str, path: &Path) -> Result<PressureStats> {
+	let missing = || Error::MissingData {
+		path: path.into(),
+		contents: line.to_string(),
+	};
+
+	let (mut avg10, mut avg60, mut avg300, mut total) = (None, None, None, None);
+
+	for field in line.split_whitespace().skip(1) {
+		let (key, value) = field.split_once('=').ok_or_else(missing)?;
+		let parse_percent = || -> Result<Percent> {
+			value.parse().map_err(|err| Error::ParseFloat {
+				path: path.into(),
+				contents: line.to_string(),
+				source: err,
+			})
+		};
+
+		match key {
+			"avg10" => avg10 = Some(parse_percent()?),
+			"avg60" => avg60 = Some(parse_percent()?),
+			"avg300" => avg300 = Some(parse_percent()?),
+			"total" => {
+				let micros = value.parse().map_err(|err| Error::ParseInt {
+					path: path.into(),
+					contents: line.to_string(),
+					source: err,
+				})?;
+				total = Some(Duration::from_micros(micros));
+			}
+			_ => {}
+		}
+	}
+
+	match (avg10, avg60, avg300, total) {
+		(Some(avg10), Some(avg60), Some(avg300), Some(total)) => Ok(PressureStats {
+			avg10,
+			avg60,
+			avg300,
+			total,
+		}),
+		_ => Err(missing()),
+	}
+}
+
+pub(crate) fn parse_pressure(contents: &str, path: &Path) -> Result<Pressure> {
+	let mut some = None;
+	let mut full = None;
+
+	for line in contents.lines() {
+		match line.split_whitespace().next() {
+			Some("some") => some = Some(parse_stats(line, path)?),
+			Some("full") => full = Some(parse_stats(line, path)?),
+			_ => {}
+		}
+	}
+
+	let some = some.ok_or_else(|| Error::MissingData {
+		path: path.into(),
+		contents: contents.to_string(),
+	})?;
+
+	Ok(Pressure { some, full })
+}
+
+pub(crate) fn pressure_path(resource: Resource) -> PathBuf {
+	Path::new(PROC_PRESSURE).join(resource.as_str())
+}
+
+pub(crate) fn cgroup_pressure_path<P>(cgroup: P, resource: Resource) -> PathBuf
+where
+	P: AsRef<Path>,
+{
+	cgroup
+		.as_ref()
+		.join(format!("{}.pressure", resource.as_str()))
+}
+
+pub(crate) fn read_pressure(path: &Path) -> Result<Pressure> {
+	parse_pressure(&read_file(path)?, path)
+}
+
+/// System-wide pressure from `/proc/pressure`.
+///
+/// Requires Linux 4.20 with PSI enabled.
+pub fn pressure(resource: Resource) -> Result<Pressure> {
+	read_pressure(&pressure_path(resource))
+}
+
+/// Pressure of a cgroup v2 directory, e.g. `/sys/fs/cgroup/system.slice`.
+pub fn cgroup_pressure<P>(cgroup: P, resource: Resource) -> Result<Pressure>
+where
+	P: AsRef<Path>,
+{
+	read_pressure(&cgroup_pressure_path(cgroup, resource))
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use float_cmp::approx_eq;
+
+	#[test]
+	fn test_parse_pressure() {
+		let contents = "some avg10=7.60 avg60=3.96 avg300=3.01 total=31996536\n\
+		                full avg10=0.12 avg60=0.08 avg300=0.09 total=5131572\n";
+		let pressure = parse_pressure(contents, Path::new("/proc/pressure/io")).unwrap();
+
+		assert!(approx_eq!(Percent, pressure.some().avg10(), 7.60));
+		assert!(approx_eq!(Percent, pressure.some().avg300(), 3.01));
+		assert_eq!(pressure.some().total(), Duration::from_micros(31996536));
+		assert!(approx_eq!(Percent, pressure.full().unwrap().avg60(), 0.08));
+
+		// cpu before Linux 5.13
+		let contents = "some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n";
+		let pressure = parse_pressure(contents, Path::new("/proc/pressure/cpu")).unwrap();
+		assert_eq!(pressure.full(), None);
+
+		assert!(parse_pressure("some avg10=0.00\n", Path::new("/proc/pressure/cpu")).is_err());
+	}
+}
@@ -1,14 +1,12 @@
-// #[cfg(feature = "serde")]
-// use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use std::collections::BTreeMap;
 
-use crate::process::{self, Process};
+use crate::process::{self, Process, ProcessField, ProcessInfo};
 use crate::{Pid, Result};
-// FIXME: Process cannot be serialized/deserialize, as a result,
-//        neither this can be.
-// #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-// #[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
 #[derive(Debug, Clone)]
 pub struct ProcessCollector {
 	pub processes: BTreeMap<Pid, Process>,
@@ -57,4 +55,13 @@ impl ProcessCollector {
 
 		Ok(())
 	}
+	/// Collects the requested fields of every process, skipping processes that exited since the
+	/// last update.
+	/// New method, not in Python psutil.
+	pub fn as_info(&self, fields: &[ProcessField]) -> BTreeMap<Pid, ProcessInfo> {
+		self.processes
+			.iter()
+			.filter_map(|(pid, process)| Some((*pid, process.as_info(fields).ok()?)))
+			.collect()
+	}
 }
@@ -0,0 +1,97 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::time::SystemTime;
+
+use crate::process::{MemoryInfo, Process, ProcessCpuTimes, ProcessError, ProcessResult, Status};
+use crate::Pid;
+
+/// Attributes that can be requested with [`Process::as_info`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessField {
+	Ppid,
+	Name,
+	Cmdline,
+	Status,
+	CpuTimes,
+	MemoryInfo,
+	CreateTime,
+}
+
+impl ProcessField {
+	/// Every available field.
+	pub const ALL: [ProcessField; 7] = [
+		ProcessField::Ppid,
+		ProcessField::Name,
+		ProcessField::Cmdline,
+		ProcessField::Status,
+		ProcessField::CpuTimes,
+		ProcessField::MemoryInfo,
+		ProcessField::CreateTime,
+	];
+}
+
+/// A snapshot of a process's attributes, similar to the dict returned by `as_dict()` in Python
+/// psutil.
+///
+/// Fields that were not requested, or that could not be retrieved because access was denied or
+/// the process is a zombie, are `None`.
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default)]
+pub struct ProcessInfo {
+	pub pid: Pid,
+	/// Also `None` if the process has no parent.
+	pub ppid: Option<Pid>,
+	pub name: Option<String>,
+	/// Also `None` for kernel threads.
+	pub cmdline: Option<Vec<String>>,
+	pub status: Option<Status>,
+	pub cpu_times: Option<ProcessCpuTimes>,
+	pub memory_info: Option<MemoryInfo>,
+	/// The process creation time as wall-clock time, unlike [`Process::create_time`].
+	pub create_time: Option<SystemTime>,
+}
+
+/// Discards errors other than `NoSuchProcess`, like `ad_value` in Python psutil.
+fn ignore_denied<T>(result: ProcessResult<T>) -> ProcessResult<Option<T>> {
+	match result {
+		Ok(value) => Ok(Some(value)),
+		Err(e @ ProcessError::NoSuchProcess { .. }) => Err(e),
+		Err(_) => Ok(None),
+	}
+}
+
+impl Process {
+	/// Collects the requested fields into a `ProcessInfo`.
+	/// Pass `&ProcessField::ALL` to retrieve everything.
+	///
+	/// Returns an error only if the process no longer exists.
+	pub fn as_info(&self, fields: &[ProcessField]) -> ProcessResult<ProcessInfo> {
+		let mut info = ProcessInfo {
+			pid: self.pid,
+			..Default::default()
+		};
+
+		for field in fields {
+			match field {
+				ProcessField::Ppid => info.ppid = ignore_denied(self.ppid())?.flatten(),
+				ProcessField::Name => info.name = ignore_denied(self.name())?,
+				ProcessField::Cmdline => {
+					info.cmdline = ignore_denied(self.cmdline_vec())?.flatten()
+				}
+				ProcessField::Status => info.status = ignore_denied(self.status())?,
+				ProcessField::CpuTimes => info.cpu_times = ignore_denied(self.cpu_times())?,
+				ProcessField::MemoryInfo => info.memory_info = ignore_denied(self.memory_info())?,
+				ProcessField::CreateTime => {
+					info.create_time = ignore_denied(self.sys_create_time_wall())?
+				}
+			}
+		}
+
+		Ok(info)
+	}
+}
@@ -1,6 +1,7 @@
 mod collector;
 mod cpu_times;
 mod errors;
+mod info;
 mod memory;
 mod open_file;
 pub mod os;
@@ -14,6 +15,7 @@ pub use nix::sys::signal::Signal;
 pub use collector::*;
 pub use cpu_times::*;
 pub use errors::*;
+pub use info::*;
 pub use memory::*;
 pub use open_file::*;
 pub use process::*;
@@ -7,6 +7,7 @@ use crate::Fd;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug)]
 pub struct OpenFile {
 	pub path: PathBuf,
 	pub fd: Option<Fd>,
@@ -1,9 +1,11 @@
 mod cpu_times;
+mod numa_maps;
 mod oneshot;
 mod process;
 mod procfs;
 
 pub use cpu_times::*;
+pub use numa_maps::*;
 pub use oneshot::*;
 pub use process::*;
 pub use procfs::*;
@@ -0,0 +1,137 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::{Bytes, Error, Result};
+
+const NUMA_MAPS: &str = "numa_maps";
+
+/// Resident memory of a process per NUMA node, summarized from `/proc/[pid]/numa_maps`.
+///
+/// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NumaMaps {
+	pub(crate) resident: BTreeMap<u32, Bytes>,
+	pub(crate) anon: Bytes,
+	pub(crate) file: Bytes,
+}
+
+impl NumaMaps {
+	/// Resident memory by node id. Nodes without any of the process's pages are missing.
+	pub fn resident(&self) -> &BTreeMap<u32, Bytes> {
+		&self.resident
+	}
+
+	/// Resident memory on all nodes.
+	pub fn total(&self) -> Bytes {
+		self.resident.values().sum()
+	}
+
+	/// Share of the resident memory that is on `node`, between 0 and 1.
+	pub fn fraction(&self, node: u32) -> f64 {
+		match self.total() {
+			0 => 0.0,
+			total => self.resident.get(&node).copied().unwrap_or_default() as f64 / total as f64,
+		}
+	}
+
+	/// Anonymous memory on all nodes.
+	pub fn anon(&self) -> Bytes {
+		self.anon
+	}
+
+	/// Resident memory of file mappings on all nodes.
+	pub fn file(&self) -> Bytes {
+		self.file
+	}
+}
+
+impl FromStr for NumaMaps {
+	type Err = Error;
+
+	fn from_str(contents: &str) -> Result<Self> {
+		let mut numa_maps = NumaMaps::default();
+
+		for line in contents.lines() {
+			let parse = |value: &str| -> Result<u64> {
+				value.parse().map_err(|err| Error::ParseInt {
+					path: NUMA_MAPS.into(),
+					contents: line.to_string(),
+					source: err,
+				})
+			};
+
+			// the counters are the trailing `key=number` fields, so a path with spaces in
+			// `file=` can't be mistaken for one of them
+			let mut counters: Vec<(&str, &str)> = line
+				.split_whitespace()
+				.rev()
+				.map_while(|field| {
+					let (key, value) = field.split_once('=')?;
+					if key == "file" || !value.bytes().all(|b| b.is_ascii_digit()) {
+						return None;
+					}
+					Some((key, value))
+				})
+				.collect();
+			counters.reverse();
+
+			let page_size = match counters.iter().find(|(key, _)| *key == "kernelpagesize_kB") {
+				Some((_, kib)) => parse(kib)? * 1024,
+				// mappings without any resident pages
+				None => continue,
+			};
+			let is_file = line.contains(" file=");
+
+			let mut anon = 0;
+			let mut resident = 0;
+			for (key, value) in counters {
+				if key == "anon" {
+					anon += parse(value)? * page_size;
+				} else if let Some(node) = key.strip_prefix('N') {
+					let node = parse(node)? as u32;
+					let bytes = parse(value)? * page_size;
+
+					*numa_maps.resident.entry(node).or_default() += bytes;
+					resident += bytes;
+				}
+			}
+
+			numa_maps.anon += anon;
+			// private copies of a file's pages are anonymous
+			if is_file {
+				numa_maps.file += resident.saturating_sub(anon);
+			}
+		}
+
+		Ok(numa_maps)
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_numa_maps() {
+		let contents = "\
+55c97911b000 default file=/usr/bin/cat mapped=2 N0=2 kernelpagesize_kB=4
+55c979125000 default file=/usr/bin/cat anon=1 dirty=1 active=0 N0=1 kernelpagesize_kB=4
+7f1000000000 default file=/opt/my app/lib x.so mapped=3 mapmax=2 N1=3 kernelpagesize_kB=4
+7f0000000000 interleave:0-1 anon=6 dirty=6 N0=2 N1=4 kernelpagesize_kB=4
+7f2000000000 bind:1 anon=1 dirty=1 N1=1 kernelpagesize_kB=2048
+7ffd3b5e1000 default
+";
+		let numa_maps = NumaMaps::from_str(contents).unwrap();
+
+		assert_eq!(numa_maps.resident()[&0], 5 * 4096);
+		assert_eq!(numa_maps.resident()[&1], 7 * 4096 + 2048 * 1024);
+		assert_eq!(numa_maps.anon(), 7 * 4096 + 2048 * 1024);
+		assert_eq!(numa_maps.file(), 5 * 4096);
+		assert_eq!(numa_maps.fraction(2), 0.0);
+	}
+}
@@ -24,21 +24,25 @@ impl Oneshot for Process {
 	/// Returns the cpu percent since the process was created, replaced, or since the last time this
 	/// method was called.
 	/// Differs from Python psutil since there is no interval argument.
+	/// The first call on a deserialized process returns 0.0 and starts a new measurement.
 	fn cpu_percent_oneshot(&mut self) -> Percent {
 		let busy = self.cpu_times_oneshot().busy();
 		let instant = Instant::now();
 
-		let percent = duration_percent(
-			// have to use checked_sub since CPU times can decrease over time at least on Linux
-			// https://github.com/cjbassi/ytop/issues/34
-			// TODO: figure out why. hibernation? something to do with running VMs?
-			busy.checked_sub(self.busy).unwrap_or_default(),
-			// TODO: can duration be zero if cpu_percent is called consecutively without allowing
-			// 		enough time to pass? Cause then we have division by zero.
-			instant - self.instant,
-		);
-
-		self.busy = busy;
+		let percent = match self.busy {
+			Some(prev_busy) => duration_percent(
+				// have to use checked_sub since CPU times can decrease over time at least on Linux
+				// https://github.com/cjbassi/ytop/issues/34
+				// TODO: figure out why. hibernation? something to do with running VMs?
+				busy.checked_sub(prev_busy).unwrap_or_default(),
+				// TODO: can duration be zero if cpu_percent is called consecutively without allowing
+				// 		enough time to pass? Cause then we have division by zero.
+				instant - self.instant,
+			),
+			None => 0.0,
+		};
+
+		self.busy = Some(busy);
 		self.instant = instant;
 
 		percent
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 
 use crate::process::os::linux::{
-	procfs_stat, procfs_statm, procfs_status, ProcfsStat, ProcfsStatm, ProcfsStatus,
+	procfs_smaps, procfs_stat, procfs_statm, procfs_status, NumaMaps, ProcfsSmaps, ProcfsStat,
+	ProcfsStatm, ProcfsStatus,
 };
 use crate::process::{psutil_error_to_process_error, Process, ProcessResult};
 use crate::{read_file, Error, Result};
@@ -46,6 +48,10 @@ pub trait ProcessExt {
 
 	fn memory_maps(&self);
 
+	/// New method, not in Python psutil.
+	/// Resident memory per NUMA node.
+	fn numa_maps(&self) -> ProcessResult<NumaMaps>;
+
 	/// New method, not in Python psutil
 	fn procfs_stat(&self) -> ProcessResult<ProcfsStat>;
 
@@ -54,6 +60,9 @@ pub trait ProcessExt {
 
 	/// New method, not in Python psutil
 	fn procfs_status(&self) -> ProcessResult<ProcfsStatus>;
+
+	/// New method, not in Python psutil
+	fn procfs_smaps(&self) -> ProcessResult<ProcfsSmaps>;
 }
 
 impl ProcessExt for Process {
@@ -100,6 +109,13 @@ impl ProcessExt for Process {
 		todo!()
 	}
 
+	fn numa_maps(&self) -> ProcessResult<NumaMaps> {
+		let contents = read_file(self.procfs_path("numa_maps"))
+			.map_err(|e| psutil_error_to_process_error(e, self.pid))?;
+
+		NumaMaps::from_str(&contents).map_err(|e| psutil_error_to_process_error(e, self.pid))
+	}
+
 	fn procfs_stat(&self) -> ProcessResult<ProcfsStat> {
 		procfs_stat(self.pid)
 	}
@@ -111,6 +127,10 @@ impl ProcessExt for Process {
 	fn procfs_status(&self) -> ProcessResult<ProcfsStatus> {
 		procfs_status(self.pid)
 	}
+
+	fn procfs_smaps(&self) -> ProcessResult<ProcfsSmaps> {
+		procfs_smaps(self.pid)
+	}
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
+mod smaps;
 mod stat;
 mod statm;
 mod status;
 
+pub use smaps::*;
 pub use stat::*;
 pub use statm::*;
 pub use status::*;
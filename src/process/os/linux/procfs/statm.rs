@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use std::str::FromStr;
 
 use crate::process::{procfs_path, psutil_error_to_process_error, ProcessResult};
@@ -9,6 +12,8 @@ const STATM: &str = "statm";
 ///
 /// The `lib` [4, u64] and `dt` [6, u64] fields are ignored.
 /// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
 #[derive(Clone, Debug)]
 pub struct ProcfsStatm {
 	/// Total program size (bytes).
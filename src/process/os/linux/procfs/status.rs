@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use std::collections::HashMap;
 use std::str::FromStr;
 
@@ -9,6 +12,8 @@ const STATUS: &str = "status";
 
 // TODO: rest of the fields
 /// New struct, not in Python psutil.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
 #[derive(Clone, Debug)]
 pub struct ProcfsStatus {
 	pub uid: [Uid; 4],
@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::process::{Process, ProcessResult};
 use crate::Count;
 
@@ -7,12 +10,18 @@ use crate::process::os::linux::{ProcessExt as _, ProcfsStatus};
 pub type Uid = u32;
 pub type Gid = u32;
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Copy, Debug)]
 pub struct Uids {
 	pub real: Uid,
 	pub effective: Uid,
 	pub saved: Uid,
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[derive(Clone, Copy, Debug)]
 pub struct Gids {
 	pub real: Gid,
 	pub effective: Gid,
@@ -1,5 +1,5 @@
-// #[cfg(feature = "serde")]
-// use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use std::cmp;
 use std::hash::{Hash, Hasher};
@@ -21,15 +21,18 @@ use crate::{Count, Percent, Pid};
 #[cfg(target_os = "linux")]
 use crate::process::os::linux::ProcfsStat;
 
-// #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-// #[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "renamed_serde"))]
 #[derive(Clone, Debug)]
 pub struct Process {
 	pub(crate) pid: Pid,
 	pub(crate) create_time: Duration,
-	pub(crate) busy: Duration,
-	// FIXME: Instant does not serialize/deserialize, cannot add serde
-	// #[cfg_attr(feature = "serde", serde(skip))]
+	// The cpu time and instant of the last cpu percent measurement. Instant has no meaning outside
+	// of the current process, so both are skipped and a deserialized process starts measuring
+	// cpu percent on the first call.
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub(crate) busy: Option<Duration>,
+	#[cfg_attr(feature = "serde", serde(skip, default = "Instant::now"))]
 	pub(crate) instant: Instant,
 
 	#[cfg(target_os = "linux")]
@@ -133,21 +136,25 @@ impl Process {
 	/// Returns the cpu percent since the process was created, replaced, or since the last time this
 	/// method was called.
 	/// Differs from Python psutil since there is no interval argument.
+	/// The first call on a deserialized process returns 0.0 and starts a new measurement.
 	pub fn cpu_percent(&mut self) -> ProcessResult<Percent> {
 		let busy = self.cpu_times()?.busy();
 		let instant = Instant::now();
 
-		let percent = duration_percent(
-			// have to use checked_sub since CPU times can decrease over time at least on Linux
-			// https://github.com/cjbassi/ytop/issues/34
-			// TODO: figure out why. hibernation? something to do with running VMs?
-			busy.checked_sub(self.busy).unwrap_or_default(),
-			// TODO: can duration be zero if cpu_percent is called consecutively without allowing
-			// 		enough time to pass? Cause then we have division by zero.
-			instant - self.instant,
-		);
-
-		self.busy = busy;
+		let percent = match self.busy {
+			Some(prev_busy) => duration_percent(
+				// have to use checked_sub since CPU times can decrease over time at least on Linux
+				// https://github.com/cjbassi/ytop/issues/34
+				// TODO: figure out why. hibernation? something to do with running VMs?
+				busy.checked_sub(prev_busy).unwrap_or_default(),
+				// TODO: can duration be zero if cpu_percent is called consecutively without allowing
+				// 		enough time to pass? Cause then we have division by zero.
+				instant - self.instant,
+			),
+			None => 0.0,
+		};
+
+		self.busy = Some(busy);
 		self.instant = instant;
 
 		Ok(percent)
@@ -309,7 +316,9 @@ impl Hash for Process {
 #[cfg(test)]
 mod unit_tests {
 	use super::*;
-	use crate::process::processes;
+	#[cfg(feature = "serde")]
+	use crate::process::ProcessInfo;
+	use crate::process::{processes, ProcessField};
 
 	#[test]
 	fn test_process_exe() {
@@ -326,6 +335,39 @@ mod unit_tests {
 		assert!(Process::current().unwrap().cwd().is_ok());
 	}
 
+	#[test]
+	fn test_process_as_info() {
+		let process = Process::current().unwrap();
+		let info = process.as_info(&[ProcessField::Name]).unwrap();
+		assert_eq!(info.pid, process.pid());
+		assert_eq!(info.name, Some(process.name().unwrap()));
+		assert!(info.cmdline.is_none());
+
+		let info = process.as_info(&ProcessField::ALL).unwrap();
+		assert!(info.create_time.unwrap() <= std::time::SystemTime::now());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_process_serde() {
+		let process = Process::current().unwrap();
+		let json = serde_json::to_string(&process).unwrap();
+		let mut deserialized: Process = serde_json::from_str(&json).unwrap();
+		assert_eq!(deserialized, process);
+		// the cpu time used before serializing isn't attributed to the time since deserializing
+		assert_eq!(deserialized.cpu_percent().unwrap(), 0.0);
+		let percent = deserialized.cpu_percent().unwrap();
+		assert!(percent.is_finite() && percent >= 0.0);
+
+		let info = process.as_info(&ProcessField::ALL).unwrap();
+		let json = serde_json::to_string(&info).unwrap();
+		let deserialized: ProcessInfo = serde_json::from_str(&json).unwrap();
+		assert_eq!(deserialized.pid, info.pid);
+		assert_eq!(deserialized.name, info.name);
+		assert_eq!(deserialized.cmdline, info.cmdline);
+		assert_eq!(deserialized.create_time, info.create_time);
+	}
+
 	#[test]
 	fn test_process_equality() {
 		assert_eq!(Process::current().unwrap(), Process::current().unwrap());
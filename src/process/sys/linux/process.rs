@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 use std::string::ToString;
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
+
+use once_cell::sync::OnceCell;
 
 use crate::common::NetConnectionType;
 use crate::process::os::linux::{procfs_stat, ProcessExt as _};
@@ -10,11 +12,18 @@ use crate::process::{
 };
 use crate::{read_dir, read_file, read_link, Count, Percent, Pid, Result};
 
+static BOOT_TIME: OnceCell<SystemTime> = OnceCell::new();
+
 /// Returns a path to a file in `/proc/[pid]/`.
 pub(crate) fn procfs_path(pid: Pid, name: &str) -> PathBuf {
 	PathBuf::from("/proc").join(pid.to_string()).join(name)
 }
 
+/// Cached since it's needed once per process.
+fn boot_time() -> Result<SystemTime> {
+	BOOT_TIME.get_or_try_init(crate::linux::boot_time).copied()
+}
+
 impl Process {
 	pub(crate) fn sys_new(pid: Pid) -> ProcessResult<Process> {
 		let procfs_stat = procfs_stat(pid)?;
@@ -26,7 +35,7 @@ impl Process {
 		Ok(Process {
 			pid,
 			create_time,
-			busy,
+			busy: Some(busy),
 			instant,
 			procfs_stat,
 		})
@@ -68,6 +77,12 @@ impl Process {
 		Ok(Some(split))
 	}
 
+	pub(crate) fn sys_create_time_wall(&self) -> ProcessResult<SystemTime> {
+		let boot_time = boot_time().map_err(|e| psutil_error_to_process_error(e, self.pid))?;
+
+		Ok(boot_time + self.create_time)
+	}
+
 	pub(crate) fn sys_parents(&self) -> Option<Vec<Process>> {
 		todo!()
 	}
@@ -4,7 +4,7 @@
 use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::path::PathBuf;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use nix::libc;
 
@@ -68,7 +68,7 @@ fn process_new(kinfo_proc: kinfo_proc) -> ProcessResult<Process> {
 	Ok(Process {
 		pid,
 		create_time,
-		busy,
+		busy: Some(busy),
 		instant,
 	})
 }
@@ -107,6 +107,11 @@ impl Process {
 		todo!()
 	}
 
+	pub(crate) fn sys_create_time_wall(&self) -> ProcessResult<SystemTime> {
+		// `p_starttime` is already relative to the epoch
+		Ok(UNIX_EPOCH + self.create_time)
+	}
+
 	pub(crate) fn sys_parents(&self) -> Option<Vec<Process>> {
 		todo!()
 	}
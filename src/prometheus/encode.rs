@@ -0,0 +1,108 @@
+use std::fmt::Write;
+
+use crate::prometheus::MetricFamily;
+
+/// Content type of the text exposition format produced by [`encode`].
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+fn escape_help(help: &str) -> String {
+	help.replace('\\', r"\\").replace('\n', r"\n")
+}
+
+fn escape_label_value(value: &str) -> String {
+	value
+		.replace('\\', r"\\")
+		.replace('"', r#"\""#)
+		.replace('\n', r"\n")
+}
+
+pub(crate) fn format_value(value: f64) -> String {
+	if value.is_nan() {
+		"NaN".to_string()
+	} else if value.is_infinite() {
+		if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+	} else {
+		value.to_string()
+	}
+}
+
+pub(crate) fn format_labels(labels: &[(String, String)]) -> String {
+	if labels.is_empty() {
+		return String::new();
+	}
+
+	let labels = labels
+		.iter()
+		.map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+		.collect::<Vec<_>>()
+		.join(",");
+
+	format!("{{{}}}", labels)
+}
+
+/// Renders metric families in the Prometheus text exposition format (version 0.0.4).
+///
+/// Families without samples are skipped.
+pub fn encode(families: &[MetricFamily]) -> String {
+	let mut out = String::new();
+
+	for family in families.iter().filter(|family| !family.samples.is_empty()) {
+		// writing to a `String` cannot fail
+		let _ = writeln!(out, "# HELP {} {}", family.name, escape_help(&family.help));
+		let _ = writeln!(
+			out,
+			"# TYPE {} {}",
+			family.name,
+			family.metric_type.as_str()
+		);
+
+		for sample in &family.samples {
+			let _ = writeln!(
+				out,
+				"{}{} {}",
+				family.name,
+				format_labels(&sample.labels),
+				format_value(sample.value)
+			);
+		}
+	}
+
+	out
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use crate::prometheus::MetricType;
+
+	#[test]
+	fn test_encode() {
+		let families = vec![
+			MetricFamily::new("node_load1", "1m load average.", MetricType::Gauge).sample(&[], 0.5),
+			MetricFamily::new("empty", "Skipped.", MetricType::Gauge),
+			MetricFamily::new(
+				"node_filesystem_size_bytes",
+				"Filesystem size in bytes.",
+				MetricType::Gauge,
+			)
+			.sample(&[("mountpoint", "/mnt/a \"b\"\\c")], 1024.0),
+		];
+
+		assert_eq!(
+			encode(&families),
+			"# HELP node_load1 1m load average.\n\
+			 # TYPE node_load1 gauge\n\
+			 node_load1 0.5\n\
+			 # HELP node_filesystem_size_bytes Filesystem size in bytes.\n\
+			 # TYPE node_filesystem_size_bytes gauge\n\
+			 node_filesystem_size_bytes{mountpoint=\"/mnt/a \\\"b\\\"\\\\c\"} 1024\n"
+		);
+	}
+
+	#[test]
+	fn test_format_value() {
+		assert_eq!(format_value(f64::INFINITY), "+Inf");
+		assert_eq!(format_value(f64::NAN), "NaN");
+		assert_eq!(format_value(3.0), "3");
+	}
+}
@@ -0,0 +1,176 @@
+#[cfg(any(
+	feature = "cpu",
+	feature = "disk",
+	feature = "memory",
+	feature = "network",
+	all(target_os = "linux", any(feature = "host", feature = "sensors"))
+))]
+use crate::prometheus::families::*;
+use crate::prometheus::{encode, MetricFamily, MetricType};
+#[cfg(any(
+	feature = "cpu",
+	feature = "disk",
+	feature = "memory",
+	feature = "network",
+	all(target_os = "linux", any(feature = "host", feature = "sensors"))
+))]
+use crate::Result;
+
+#[cfg(feature = "cpu")]
+use crate::cpu::{self, CpuPercentCollector};
+#[cfg(feature = "disk")]
+use crate::disk::{self, DiskIoCountersCollector};
+#[cfg(all(feature = "host", target_os = "linux"))]
+use crate::host;
+#[cfg(feature = "memory")]
+use crate::memory;
+#[cfg(feature = "network")]
+use crate::network::NetIoCountersCollector;
+#[cfg(all(feature = "sensors", target_os = "linux"))]
+use crate::sensors;
+
+/// Gathers every metric supported by the enabled features.
+///
+/// Holds the collectors needed to report cpu percent and overflow-corrected I/O counters, so a
+/// single `Exporter` should be reused between scrapes.
+///
+/// ```no_run
+/// let mut exporter = psutil::prometheus::Exporter::new();
+/// print!("{}", exporter.render());
+/// ```
+#[derive(Debug, Default)]
+pub struct Exporter {
+	#[cfg(feature = "cpu")]
+	cpu_percent_collector: Option<CpuPercentCollector>,
+	#[cfg(feature = "disk")]
+	disk_io_counters_collector: DiskIoCountersCollector,
+	#[cfg(feature = "network")]
+	net_io_counters_collector: NetIoCountersCollector,
+}
+
+impl Exporter {
+	pub fn new() -> Exporter {
+		Exporter::default()
+	}
+
+	/// Collects all metric families.
+	///
+	/// A failing collector does not fail the whole scrape, it is reported through
+	/// `node_scrape_collector_success` instead, like node_exporter does.
+	pub fn gather(&mut self) -> Vec<MetricFamily> {
+		let mut families = Vec::new();
+		let mut success = MetricFamily::new(
+			"node_scrape_collector_success",
+			"Whether a collector succeeded.",
+			MetricType::Gauge,
+		);
+
+		self.collect(&mut families, &mut success);
+		families.push(success);
+
+		families
+	}
+
+	/// Runs every collector, adding their families to `families` and their outcome to `success`.
+	#[cfg(any(
+		feature = "cpu",
+		feature = "disk",
+		feature = "memory",
+		feature = "network",
+		all(target_os = "linux", any(feature = "host", feature = "sensors"))
+	))]
+	fn collect(&mut self, families: &mut Vec<MetricFamily>, success: &mut MetricFamily) {
+		let mut collect = |name: &str, result: Result<Vec<MetricFamily>>| {
+			success.push(&[("collector", name)], result.is_ok() as u8 as f64);
+			if let Ok(collected) = result {
+				families.extend(collected);
+			}
+		};
+
+		#[cfg(feature = "cpu")]
+		{
+			collect(
+				"cpu",
+				cpu::cpu_times_percpu().map(|t| cpu_times_metrics(&t)),
+			);
+
+			// the first call only initializes the collector, so it has nothing to report yet
+			let cpu_percent = match &mut self.cpu_percent_collector {
+				Some(collector) => collector.cpu_percent_percpu(),
+				None => CpuPercentCollector::new().map(|collector| {
+					self.cpu_percent_collector = Some(collector);
+					Vec::new()
+				}),
+			};
+			collect("cpu_percent", cpu_percent.map(|p| cpu_percent_metrics(&p)));
+		}
+
+		#[cfg(feature = "memory")]
+		{
+			collect(
+				"meminfo",
+				memory::virtual_memory().map(|m| virtual_memory_metrics(&m)),
+			);
+			collect(
+				"swap",
+				memory::swap_memory().map(|m| swap_memory_metrics(&m)),
+			);
+		}
+
+		#[cfg(feature = "disk")]
+		{
+			collect(
+				"diskstats",
+				self.disk_io_counters_collector
+					.disk_io_counters_per_partition()
+					.map(|c| disk_io_counters_metrics(&c)),
+			);
+
+			let usage = disk::disk_usage_all().map(|usage_per_partition| {
+				// partitions that can't be queried (e.g. missing permissions) are left out
+				let usage: Vec<_> = usage_per_partition
+					.into_iter()
+					.filter_map(|(partition, usage)| Some((partition, usage.ok()?)))
+					.collect();
+
+				disk_usage_metrics(&usage)
+			});
+			collect("filesystem", usage);
+		}
+
+		#[cfg(feature = "network")]
+		collect(
+			"netdev",
+			self.net_io_counters_collector
+				.net_io_counters_pernic()
+				.map(|c| net_io_counters_metrics(&c)),
+		);
+
+		#[cfg(all(feature = "host", target_os = "linux"))]
+		collect("loadavg", host::loadavg().map(|l| loadavg_metrics(&l)));
+
+		#[cfg(all(feature = "sensors", target_os = "linux"))]
+		{
+			let temperatures: Vec<_> = sensors::temperatures()
+				.into_iter()
+				.filter_map(|sensor| sensor.ok())
+				.collect();
+			collect("hwmon", Ok(temperature_metrics(&temperatures)));
+		}
+	}
+
+	/// Without any collector enabled, only `node_scrape_collector_success` is reported.
+	#[cfg(not(any(
+		feature = "cpu",
+		feature = "disk",
+		feature = "memory",
+		feature = "network",
+		all(target_os = "linux", any(feature = "host", feature = "sensors"))
+	)))]
+	fn collect(&mut self, _families: &mut Vec<MetricFamily>, _success: &mut MetricFamily) {}
+
+	/// Collects all metric families and renders them in the text exposition format.
+	pub fn render(&mut self) -> String {
+		encode(&self.gather())
+	}
+}
@@ -0,0 +1,450 @@
+//! Conversions from the crate's structs into metric families, using the same names and labels as
+//! node_exporter where an equivalent metric exists.
+
+#[cfg(any(
+	feature = "disk",
+	feature = "network",
+	all(feature = "sensors", target_os = "linux")
+))]
+use std::collections::HashMap;
+
+use crate::prometheus::{MetricFamily, MetricType};
+
+#[cfg(feature = "cpu")]
+use crate::cpu::CpuTimes;
+#[cfg(feature = "disk")]
+use crate::disk::{DiskIoCounters, DiskUsage, Partition};
+#[cfg(all(feature = "host", target_os = "linux"))]
+use crate::host::LoadAvg;
+#[cfg(feature = "memory")]
+use crate::memory::{SwapMemory, VirtualMemory};
+#[cfg(feature = "network")]
+use crate::network::NetIoCounters;
+#[cfg(feature = "process")]
+use crate::process::ProcessCollector;
+#[cfg(all(feature = "sensors", target_os = "linux"))]
+use crate::sensors::TemperatureSensor;
+#[cfg(feature = "cpu")]
+use crate::Percent;
+
+#[cfg(any(feature = "disk", feature = "network"))]
+fn sorted<T>(map: &HashMap<String, T>) -> Vec<(&String, &T)> {
+	let mut entries: Vec<_> = map.iter().collect();
+	entries.sort_by(|a, b| a.0.cmp(b.0));
+	entries
+}
+
+/// `node_cpu_seconds_total` for each cpu and mode.
+#[cfg(feature = "cpu")]
+pub fn cpu_times_metrics(cpu_times_percpu: &[CpuTimes]) -> Vec<MetricFamily> {
+	let mut family = MetricFamily::new(
+		"node_cpu_seconds_total",
+		"Seconds the CPUs spent in each mode.",
+		MetricType::Counter,
+	);
+
+	for (i, cpu_times) in cpu_times_percpu.iter().enumerate() {
+		let cpu = i.to_string();
+		// `idle()` includes iowait on Linux, node_exporter reports them separately
+		let mut modes = vec![
+			("user", cpu_times.user),
+			("nice", cpu_times.nice),
+			("system", cpu_times.system),
+			("idle", cpu_times.idle),
+		];
+		#[cfg(target_os = "linux")]
+		{
+			modes.push(("iowait", cpu_times.iowait));
+			modes.push(("irq", cpu_times.irq));
+			modes.push(("softirq", cpu_times.softirq));
+			if let Some(steal) = cpu_times.steal {
+				modes.push(("steal", steal));
+			}
+		}
+
+		for (mode, duration) in modes {
+			family.push(&[("cpu", &cpu), ("mode", mode)], duration.as_secs_f64());
+		}
+	}
+
+	vec![family]
+}
+
+/// `psutil_cpu_percent` for each cpu. Not exported by node_exporter.
+#[cfg(feature = "cpu")]
+pub fn cpu_percent_metrics(cpu_percent_percpu: &[Percent]) -> Vec<MetricFamily> {
+	let mut family = MetricFamily::new(
+		"psutil_cpu_percent",
+		"Percent of time the CPU was busy since the previous scrape.",
+		MetricType::Gauge,
+	);
+
+	for (i, percent) in cpu_percent_percpu.iter().enumerate() {
+		family.push(&[("cpu", &i.to_string())], *percent as f64);
+	}
+
+	vec![family]
+}
+
+#[cfg(feature = "memory")]
+fn memory_gauge(field: &str, value: u64) -> MetricFamily {
+	MetricFamily::new(
+		format!("node_memory_{}_bytes", field),
+		format!("Memory information field {}_bytes.", field),
+		MetricType::Gauge,
+	)
+	.sample(&[], value as f64)
+}
+
+/// `node_memory_*_bytes`, named after the matching `/proc/meminfo` fields.
+#[cfg(feature = "memory")]
+pub fn virtual_memory_metrics(virtual_memory: &VirtualMemory) -> Vec<MetricFamily> {
+	#[allow(unused_mut)]
+	let mut families = vec![
+		memory_gauge("MemTotal", virtual_memory.total()),
+		memory_gauge("MemAvailable", virtual_memory.available()),
+		memory_gauge("MemFree", virtual_memory.free()),
+	];
+
+	#[cfg(target_os = "linux")]
+	families.extend(vec![
+		memory_gauge("Active", virtual_memory.active),
+		memory_gauge("Inactive", virtual_memory.inactive),
+		memory_gauge("Buffers", virtual_memory.buffers),
+		memory_gauge("Cached", virtual_memory.cached),
+	]);
+	#[cfg(target_os = "linux")]
+	families.extend(
+		virtual_memory
+			.shared
+			.map(|shared| memory_gauge("Shmem", shared)),
+	);
+
+	families
+}
+
+/// `node_memory_Swap*_bytes` and the `node_vmstat_pswp*` page counters.
+#[cfg(feature = "memory")]
+pub fn swap_memory_metrics(swap_memory: &SwapMemory) -> Vec<MetricFamily> {
+	// `swapped_in` and `swapped_out` are already in pages on Linux
+	#[cfg(target_os = "linux")]
+	let pages = |value: u64| value as f64;
+	#[cfg(not(target_os = "linux"))]
+	let pages = |value: u64| value as f64 / *crate::PAGE_SIZE as f64;
+
+	vec![
+		memory_gauge("SwapTotal", swap_memory.total()),
+		memory_gauge("SwapFree", swap_memory.free()),
+		MetricFamily::new(
+			"node_vmstat_pswpin",
+			"/proc/vmstat information field pswpin.",
+			MetricType::Counter,
+		)
+		.sample(&[], pages(swap_memory.swapped_in())),
+		MetricFamily::new(
+			"node_vmstat_pswpout",
+			"/proc/vmstat information field pswpout.",
+			MetricType::Counter,
+		)
+		.sample(&[], pages(swap_memory.swapped_out())),
+	]
+}
+
+/// `node_disk_*` counters labelled by device.
+#[cfg(feature = "disk")]
+pub fn disk_io_counters_metrics(
+	disk_io_counters_per_partition: &HashMap<String, DiskIoCounters>,
+) -> Vec<MetricFamily> {
+	#[allow(clippy::type_complexity)]
+	let mut fields: Vec<(&str, &str, fn(&DiskIoCounters) -> f64)> = vec![
+		(
+			"node_disk_reads_completed_total",
+			"The total number of reads completed successfully.",
+			|c| c.read_count as f64,
+		),
+		(
+			"node_disk_writes_completed_total",
+			"The total number of writes completed successfully.",
+			|c| c.write_count as f64,
+		),
+		(
+			"node_disk_read_bytes_total",
+			"The total number of bytes read successfully.",
+			|c| c.read_bytes as f64,
+		),
+		(
+			"node_disk_written_bytes_total",
+			"The total number of bytes written successfully.",
+			|c| c.write_bytes as f64,
+		),
+		(
+			"node_disk_read_time_seconds_total",
+			"The total number of seconds spent by all reads.",
+			|c| c.read_time.as_secs_f64(),
+		),
+		(
+			"node_disk_write_time_seconds_total",
+			"This is the total number of seconds spent by all writes.",
+			|c| c.write_time.as_secs_f64(),
+		),
+	];
+
+	#[cfg(target_os = "linux")]
+	fields.extend(vec![
+		(
+			"node_disk_io_time_seconds_total",
+			"Total seconds spent doing I/Os.",
+			(|c| c.busy_time.as_secs_f64()) as fn(&DiskIoCounters) -> f64,
+		),
+		(
+			"node_disk_reads_merged_total",
+			"The total number of reads merged.",
+			|c| c.read_merged_count as f64,
+		),
+		(
+			"node_disk_writes_merged_total",
+			"The number of writes merged.",
+			|c| c.write_merged_count as f64,
+		),
+	]);
+
+	let devices = sorted(disk_io_counters_per_partition);
+
+	fields
+		.into_iter()
+		.map(|(name, help, value)| {
+			let mut family = MetricFamily::new(name, help, MetricType::Counter);
+			for (device, counters) in &devices {
+				family.push(&[("device", device)], value(counters));
+			}
+			family
+		})
+		.collect()
+}
+
+/// `node_filesystem_{size,free,avail}_bytes`, `node_filesystem_files{,_free}` and
+/// `node_filesystem_readonly` labelled by device, fstype and mountpoint.
+#[cfg(feature = "disk")]
+pub fn disk_usage_metrics(usage_per_partition: &[(Partition, DiskUsage)]) -> Vec<MetricFamily> {
+	let mut size = MetricFamily::new(
+		"node_filesystem_size_bytes",
+		"Filesystem size in bytes.",
+		MetricType::Gauge,
+	);
+	let mut free = MetricFamily::new(
+		"node_filesystem_free_bytes",
+		"Filesystem free space in bytes.",
+		MetricType::Gauge,
+	);
+	let mut avail = MetricFamily::new(
+		"node_filesystem_avail_bytes",
+		"Filesystem space available to non-root users in bytes.",
+		MetricType::Gauge,
+	);
+	let mut files = MetricFamily::new(
+		"node_filesystem_files",
+		"Filesystem total file nodes.",
+		MetricType::Gauge,
+	);
+	let mut files_free = MetricFamily::new(
+		"node_filesystem_files_free",
+		"Filesystem total free file nodes.",
+		MetricType::Gauge,
+	);
+	let mut readonly = MetricFamily::new(
+		"node_filesystem_readonly",
+		"Filesystem read-only status.",
+		MetricType::Gauge,
+	);
+
+	for (partition, usage) in usage_per_partition {
+		let mountpoint = partition.mountpoint().to_string_lossy();
+		let labels = [
+			("device", partition.device()),
+			("fstype", partition.filesystem().as_str()),
+			("mountpoint", &mountpoint),
+		];
+
+		size.push(&labels, usage.total() as f64);
+		// `DiskUsage::free` excludes the blocks reserved for root
+		free.push(&labels, (usage.total() - usage.used()) as f64);
+		avail.push(&labels, usage.free() as f64);
+		files.push(&labels, usage.inodes_total() as f64);
+		files_free.push(&labels, usage.inodes_free() as f64);
+		readonly.push(&labels, if usage.read_only() { 1.0 } else { 0.0 });
+	}
+
+	vec![size, free, avail, files, files_free, readonly]
+}
+
+/// `node_network_*` counters labelled by device.
+#[cfg(feature = "network")]
+pub fn net_io_counters_metrics(
+	net_io_counters_pernic: &HashMap<String, NetIoCounters>,
+) -> Vec<MetricFamily> {
+	#[allow(clippy::type_complexity)]
+	let fields: Vec<(&str, &str, fn(&NetIoCounters) -> u64)> = vec![
+		(
+			"node_network_receive_bytes_total",
+			"Network device statistic receive_bytes.",
+			|c| c.bytes_recv,
+		),
+		(
+			"node_network_transmit_bytes_total",
+			"Network device statistic transmit_bytes.",
+			|c| c.bytes_sent,
+		),
+		(
+			"node_network_receive_packets_total",
+			"Network device statistic receive_packets.",
+			|c| c.packets_recv,
+		),
+		(
+			"node_network_transmit_packets_total",
+			"Network device statistic transmit_packets.",
+			|c| c.packets_sent,
+		),
+		(
+			"node_network_receive_errs_total",
+			"Network device statistic receive_errs.",
+			|c| c.err_in,
+		),
+		(
+			"node_network_transmit_errs_total",
+			"Network device statistic transmit_errs.",
+			|c| c.err_out,
+		),
+		(
+			"node_network_receive_drop_total",
+			"Network device statistic receive_drop.",
+			|c| c.drop_in,
+		),
+		(
+			"node_network_transmit_drop_total",
+			"Network device statistic transmit_drop.",
+			|c| c.drop_out,
+		),
+	];
+
+	let devices = sorted(net_io_counters_pernic);
+
+	fields
+		.into_iter()
+		.map(|(name, help, value)| {
+			let mut family = MetricFamily::new(name, help, MetricType::Counter);
+			for (device, counters) in &devices {
+				family.push(&[("device", device)], value(counters) as f64);
+			}
+			family
+		})
+		.collect()
+}
+
+/// `node_load1`, `node_load5` and `node_load15`.
+#[cfg(all(feature = "host", target_os = "linux"))]
+pub fn loadavg_metrics(loadavg: &LoadAvg) -> Vec<MetricFamily> {
+	vec![
+		MetricFamily::new("node_load1", "1m load average.", MetricType::Gauge)
+			.sample(&[], loadavg.one),
+		MetricFamily::new("node_load5", "5m load average.", MetricType::Gauge)
+			.sample(&[], loadavg.five),
+		MetricFamily::new("node_load15", "15m load average.", MetricType::Gauge)
+			.sample(&[], loadavg.fifteen),
+	]
+}
+
+/// `node_hwmon_temp_{,max_,crit_}celsius` labelled by chip and sensor.
+#[cfg(all(feature = "sensors", target_os = "linux"))]
+pub fn temperature_metrics(temperatures: &[TemperatureSensor]) -> Vec<MetricFamily> {
+	let mut current = MetricFamily::new(
+		"node_hwmon_temp_celsius",
+		"Hardware monitor for temperature (input).",
+		MetricType::Gauge,
+	);
+	let mut max = MetricFamily::new(
+		"node_hwmon_temp_max_celsius",
+		"Hardware monitor for temperature (max).",
+		MetricType::Gauge,
+	);
+	let mut crit = MetricFamily::new(
+		"node_hwmon_temp_crit_celsius",
+		"Hardware monitor for temperature (crit).",
+		MetricType::Gauge,
+	);
+
+	// sensors without a label are numbered per chip so that label sets stay unique
+	let mut unlabelled: HashMap<&str, usize> = HashMap::new();
+
+	for sensor in temperatures {
+		let chip = sensor.hwmon_id().unwrap_or_else(|| sensor.unit());
+		let sensor_name = match sensor.label() {
+			Some(label) => label.to_string(),
+			None => {
+				let count = unlabelled.entry(chip).or_insert(0);
+				*count += 1;
+				format!("temp{}", count)
+			}
+		};
+		let labels = [("chip", chip), ("sensor", sensor_name.as_str())];
+
+		current.push(&labels, sensor.current().celsius());
+		if let Some(high) = sensor.high() {
+			max.push(&labels, high.celsius());
+		}
+		if let Some(critical) = sensor.critical() {
+			crit.push(&labels, critical.celsius());
+		}
+	}
+
+	vec![current, max, crit]
+}
+
+/// Per-process cpu and memory usage labelled by pid and name. Not exported by node_exporter.
+///
+/// Processes that exit while being read are left out.
+#[cfg(feature = "process")]
+pub fn process_metrics(process_collector: &mut ProcessCollector) -> Vec<MetricFamily> {
+	let mut cpu_seconds = MetricFamily::new(
+		"psutil_process_cpu_seconds_total",
+		"Seconds the process spent in user and system mode.",
+		MetricType::Counter,
+	);
+	let mut cpu_percent = MetricFamily::new(
+		"psutil_process_cpu_percent",
+		"Percent of time the process was busy since the previous collection.",
+		MetricType::Gauge,
+	);
+	let mut resident = MetricFamily::new(
+		"psutil_process_resident_memory_bytes",
+		"Resident memory size in bytes.",
+		MetricType::Gauge,
+	);
+	let mut virtual_ = MetricFamily::new(
+		"psutil_process_virtual_memory_bytes",
+		"Virtual memory size in bytes.",
+		MetricType::Gauge,
+	);
+
+	for (pid, process) in process_collector.processes.iter_mut() {
+		let (name, cpu_times, percent, memory) = match (
+			process.name(),
+			process.cpu_times(),
+			process.cpu_percent(),
+			process.memory_info(),
+		) {
+			(Ok(name), Ok(cpu_times), Ok(percent), Ok(memory)) => {
+				(name, cpu_times, percent, memory)
+			}
+			_ => continue,
+		};
+		let pid = pid.to_string();
+		let labels = [("pid", pid.as_str()), ("name", name.as_str())];
+
+		cpu_seconds.push(&labels, cpu_times.busy().as_secs_f64());
+		cpu_percent.push(&labels, percent as f64);
+		resident.push(&labels, memory.rss() as f64);
+		virtual_.push(&labels, memory.vms() as f64);
+	}
+
+	vec![cpu_seconds, cpu_percent, resident, virtual_]
+}
@@ -0,0 +1,154 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::thread;
+use std::time::Duration;
+
+use crate::prometheus::{Exporter, CONTENT_TYPE};
+
+const METRICS_PATH: &str = "/metrics";
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+/// Keeps the exporter from spinning while accepting keeps failing, e.g. out of file descriptors.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+fn write_response(
+	stream: &mut TcpStream,
+	status: &str,
+	content_type: &str,
+	body: &str,
+) -> io::Result<()> {
+	write!(
+		stream,
+		"HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+		status,
+		content_type,
+		body.len(),
+		body
+	)?;
+	stream.flush()
+}
+
+impl Exporter {
+	/// Serves the metrics on `GET /metrics`, only returns if binding `addr` fails.
+	///
+	/// Requests are handled one at a time on the calling thread, which is plenty for a scraper
+	/// polling every few seconds. Reads and writes time out after 10 seconds, so a client that
+	/// connects and sends nothing can't hold up later scrapes. Errors of single connections are
+	/// ignored, see [`serve_with`](Exporter::serve_with) to report them.
+	///
+	/// ```no_run
+	/// psutil::prometheus::Exporter::new().serve("0.0.0.0:9100").unwrap();
+	/// ```
+	pub fn serve<A>(&mut self, addr: A) -> io::Result<()>
+	where
+		A: ToSocketAddrs,
+	{
+		self.serve_with(addr, |_| {})
+	}
+
+	/// Like `serve`, but passes the errors of accepting or answering a connection to `on_error`
+	/// before moving on to the next one.
+	///
+	/// ```no_run
+	/// psutil::prometheus::Exporter::new()
+	///     .serve_with("0.0.0.0:9100", |err| eprintln!("exporter: {}", err))
+	///     .unwrap();
+	/// ```
+	pub fn serve_with<A, F>(&mut self, addr: A, mut on_error: F) -> io::Result<()>
+	where
+		A: ToSocketAddrs,
+		F: FnMut(io::Error),
+	{
+		let listener = TcpListener::bind(addr)?;
+
+		for stream in listener.incoming() {
+			let mut stream = match stream {
+				Ok(stream) => stream,
+				// e.g. `EMFILE` or `ECONNABORTED`, which go away on their own
+				Err(err) => {
+					on_error(err);
+					thread::sleep(ACCEPT_RETRY_DELAY);
+					continue;
+				}
+			};
+
+			// a misbehaving client shouldn't take the exporter down
+			let result = stream
+				.set_read_timeout(Some(IO_TIMEOUT))
+				.and_then(|_| stream.set_write_timeout(Some(IO_TIMEOUT)))
+				.and_then(|_| self.handle(&mut stream));
+			if let Err(err) = result {
+				on_error(err);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Answers a single HTTP request on `stream`.
+	pub fn handle(&mut self, stream: &mut TcpStream) -> io::Result<()> {
+		let mut reader = BufReader::new(stream.try_clone()?);
+
+		let mut request_line = String::new();
+		reader.read_line(&mut request_line)?;
+
+		// drain the headers, the request body is never used
+		let mut header = String::new();
+		while reader.read_line(&mut header)? > 2 {
+			header.clear();
+		}
+
+		let mut parts = request_line.split_whitespace();
+		let method = parts.next().unwrap_or_default();
+		let path = parts.next().unwrap_or_default();
+
+		match (method, path) {
+			("GET", METRICS_PATH) => {
+				let body = self.render();
+				write_response(stream, "200 OK", CONTENT_TYPE, &body)
+			}
+			("GET", _) => write_response(stream, "404 Not Found", "text/plain", "Not Found\n"),
+			_ => write_response(
+				stream,
+				"405 Method Not Allowed",
+				"text/plain",
+				"Method Not Allowed\n",
+			),
+		}
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use std::io::Read;
+
+	fn request(path: &str) -> String {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = listener.local_addr().unwrap();
+
+		let server = thread::spawn(move || {
+			let mut stream = listener.accept().unwrap().0;
+			Exporter::new().handle(&mut stream).unwrap();
+		});
+
+		let mut client = TcpStream::connect(addr).unwrap();
+		write!(client, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+		let mut response = String::new();
+		client.read_to_string(&mut response).unwrap();
+		server.join().unwrap();
+
+		response
+	}
+
+	#[test]
+	fn test_metrics() {
+		let response = request("/metrics");
+		assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+		assert!(response.contains("# TYPE node_scrape_collector_success gauge\n"));
+	}
+
+	#[test]
+	fn test_not_found() {
+		assert!(request("/").starts_with("HTTP/1.1 404 Not Found\r\n"));
+	}
+}
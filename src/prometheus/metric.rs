@@ -0,0 +1,92 @@
+/// The kind of a metric family, as declared by its `# TYPE` line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricType {
+	/// A monotonically increasing value, such as bytes read.
+	Counter,
+
+	/// A value that can go up and down, such as free memory.
+	Gauge,
+}
+
+impl MetricType {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			MetricType::Counter => "counter",
+			MetricType::Gauge => "gauge",
+		}
+	}
+}
+
+/// A single labelled value of a metric family.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+	pub(crate) labels: Vec<(String, String)>,
+	pub(crate) value: f64,
+}
+
+impl Sample {
+	pub fn labels(&self) -> &[(String, String)] {
+		&self.labels
+	}
+
+	pub fn value(&self) -> f64 {
+		self.value
+	}
+}
+
+/// A named group of samples sharing the same help text and type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricFamily {
+	pub(crate) name: String,
+	pub(crate) help: String,
+	pub(crate) metric_type: MetricType,
+	pub(crate) samples: Vec<Sample>,
+}
+
+impl MetricFamily {
+	pub fn new<N, H>(name: N, help: H, metric_type: MetricType) -> MetricFamily
+	where
+		N: Into<String>,
+		H: Into<String>,
+	{
+		MetricFamily {
+			name: name.into(),
+			help: help.into(),
+			metric_type,
+			samples: Vec::new(),
+		}
+	}
+
+	/// Adds a sample with the given labels.
+	pub fn sample(mut self, labels: &[(&str, &str)], value: f64) -> MetricFamily {
+		self.push(labels, value);
+		self
+	}
+
+	/// Adds a sample with the given labels.
+	pub fn push(&mut self, labels: &[(&str, &str)], value: f64) {
+		self.samples.push(Sample {
+			labels: labels
+				.iter()
+				.map(|(name, value)| (name.to_string(), value.to_string()))
+				.collect(),
+			value,
+		});
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn help(&self) -> &str {
+		&self.help
+	}
+
+	pub fn metric_type(&self) -> MetricType {
+		self.metric_type
+	}
+
+	pub fn samples(&self) -> &[Sample] {
+		&self.samples
+	}
+}
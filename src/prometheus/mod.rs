@@ -0,0 +1,31 @@
+//! Prometheus text exposition of the collected metrics.
+//!
+//! Metric names and labels follow node_exporter where an equivalent metric exists, so existing
+//! dashboards and alerts keep working.
+
+mod encode;
+mod exporter;
+#[cfg(any(
+	feature = "cpu",
+	feature = "disk",
+	feature = "memory",
+	feature = "network",
+	feature = "process",
+	all(target_os = "linux", any(feature = "host", feature = "sensors"))
+))]
+mod families;
+mod http;
+mod metric;
+
+pub use encode::*;
+pub use exporter::*;
+#[cfg(any(
+	feature = "cpu",
+	feature = "disk",
+	feature = "memory",
+	feature = "network",
+	feature = "process",
+	all(target_os = "linux", any(feature = "host", feature = "sensors"))
+))]
+pub use families::*;
+pub use metric::*;
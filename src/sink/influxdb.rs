@@ -0,0 +1,103 @@
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::prometheus::MetricFamily;
+use crate::sink::Sink;
+
+fn escape(s: &str, special: &[char]) -> String {
+	let mut escaped = String::with_capacity(s.len());
+	for c in s.chars() {
+		if c == '\\' || special.contains(&c) {
+			escaped.push('\\');
+		}
+		escaped.push(c);
+	}
+	escaped
+}
+
+/// Renders metric families in the InfluxDB line protocol.
+///
+/// Each sample becomes one line, using the family name as the measurement, the labels as tags and
+/// a single `value` field. Labels with empty values and non-finite values are dropped since the
+/// protocol forbids them.
+pub fn encode_influxdb(families: &[MetricFamily], timestamp: SystemTime) -> String {
+	let timestamp = timestamp
+		.duration_since(UNIX_EPOCH)
+		.unwrap_or_default()
+		.as_nanos();
+	let mut out = String::new();
+
+	for family in families {
+		let measurement = escape(family.name(), &[',', ' ']);
+
+		for sample in family.samples().iter().filter(|s| s.value().is_finite()) {
+			out.push_str(&measurement);
+			for (name, value) in sample
+				.labels()
+				.iter()
+				.filter(|(_, value)| !value.is_empty())
+			{
+				out.push(',');
+				out.push_str(&escape(name, &[',', '=', ' ']));
+				out.push('=');
+				out.push_str(&escape(value, &[',', '=', ' ']));
+			}
+			out.push_str(&format!(" value={:?} {}\n", sample.value(), timestamp));
+		}
+	}
+
+	out
+}
+
+/// Writes the InfluxDB line protocol to any writer, e.g. a `TcpStream` to Telegraf's
+/// `socket_listener` or a file.
+#[derive(Debug)]
+pub struct InfluxDbSink<W> {
+	writer: W,
+}
+
+impl<W> InfluxDbSink<W>
+where
+	W: Write,
+{
+	pub fn new(writer: W) -> InfluxDbSink<W> {
+		InfluxDbSink { writer }
+	}
+
+	pub fn into_inner(self) -> W {
+		self.writer
+	}
+}
+
+impl<W> Sink for InfluxDbSink<W>
+where
+	W: Write,
+{
+	fn send(&mut self, families: &[MetricFamily]) -> io::Result<()> {
+		self.writer
+			.write_all(encode_influxdb(families, SystemTime::now()).as_bytes())?;
+		self.writer.flush()
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use crate::prometheus::MetricType;
+	use std::time::Duration;
+
+	#[test]
+	fn test_encode_influxdb() {
+		let families =
+			vec![
+				MetricFamily::new("node_disk_read_bytes_total", "", MetricType::Counter)
+					.sample(&[("device", "sda 1"), ("empty", "")], 512.0),
+			];
+		let timestamp = UNIX_EPOCH + Duration::from_secs(1);
+
+		assert_eq!(
+			encode_influxdb(&families, timestamp),
+			"node_disk_read_bytes_total,device=sda\\ 1 value=512.0 1000000000\n"
+		);
+	}
+}
@@ -0,0 +1,32 @@
+//! Output sinks for the metric families produced by the `prometheus` module.
+//!
+//! Any collector output that can be turned into [`MetricFamily`]s, e.g. with
+//! [`Exporter::gather`](crate::prometheus::Exporter::gather) or
+//! [`process_metrics`](crate::prometheus::process_metrics), can be sent to any sink.
+//!
+//! ```no_run
+//! use psutil::prometheus::Exporter;
+//! use psutil::sink::{Sink, StatsdSink};
+//!
+//! let mut exporter = Exporter::new();
+//! let mut sink = StatsdSink::new("127.0.0.1:8125").unwrap();
+//! sink.send(&exporter.gather()).unwrap();
+//! ```
+
+mod influxdb;
+mod openmetrics;
+mod statsd;
+
+pub use influxdb::*;
+pub use openmetrics::*;
+pub use statsd::*;
+
+use std::io;
+
+use crate::prometheus::MetricFamily;
+
+/// A destination for metric families.
+pub trait Sink {
+	/// Sends one batch of metric families.
+	fn send(&mut self, families: &[MetricFamily]) -> io::Result<()>;
+}
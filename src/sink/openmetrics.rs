@@ -0,0 +1,116 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use crate::prometheus::{format_labels, format_value, MetricFamily, MetricType};
+use crate::sink::Sink;
+
+/// Content type of the format produced by [`encode_openmetrics`].
+pub const OPENMETRICS_CONTENT_TYPE: &str =
+	"application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Renders metric families in the OpenMetrics text format.
+///
+/// Unlike the Prometheus format, counter families are declared without their `_total` suffix,
+/// every counter sample carries it, and the output is terminated by `# EOF`.
+pub fn encode_openmetrics(families: &[MetricFamily]) -> String {
+	let mut out = String::new();
+
+	for family in families
+		.iter()
+		.filter(|family| !family.samples().is_empty())
+	{
+		let (name, suffix) = match family.metric_type() {
+			MetricType::Counter => (
+				family
+					.name()
+					.strip_suffix("_total")
+					.unwrap_or(family.name()),
+				"_total",
+			),
+			MetricType::Gauge => (family.name(), ""),
+		};
+		let help = family
+			.help()
+			.replace('\\', r"\\")
+			.replace('"', r#"\""#)
+			.replace('\n', r"\n");
+
+		// writing to a `String` cannot fail
+		let _ = writeln!(out, "# TYPE {} {}", name, family.metric_type().as_str());
+		let _ = writeln!(out, "# HELP {} {}", name, help);
+
+		for sample in family.samples() {
+			let _ = writeln!(
+				out,
+				"{}{}{} {}",
+				name,
+				suffix,
+				format_labels(sample.labels()),
+				format_value(sample.value())
+			);
+		}
+	}
+
+	out.push_str("# EOF\n");
+	out
+}
+
+/// Writes complete OpenMetrics expositions to any writer.
+#[derive(Debug)]
+pub struct OpenMetricsSink<W> {
+	writer: W,
+}
+
+impl<W> OpenMetricsSink<W>
+where
+	W: Write,
+{
+	pub fn new(writer: W) -> OpenMetricsSink<W> {
+		OpenMetricsSink { writer }
+	}
+
+	pub fn into_inner(self) -> W {
+		self.writer
+	}
+}
+
+impl<W> Sink for OpenMetricsSink<W>
+where
+	W: Write,
+{
+	fn send(&mut self, families: &[MetricFamily]) -> io::Result<()> {
+		self.writer
+			.write_all(encode_openmetrics(families).as_bytes())?;
+		self.writer.flush()
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+
+	#[test]
+	fn test_encode_openmetrics() {
+		let families = vec![
+			MetricFamily::new(
+				"node_vmstat_pswpin",
+				"Pages swapped in.",
+				MetricType::Counter,
+			)
+			.sample(&[], 3.0),
+			MetricFamily::new("node_load1", "1m load average.", MetricType::Gauge)
+				.sample(&[], 0.25),
+		];
+
+		assert_eq!(
+			encode_openmetrics(&families),
+			"# TYPE node_vmstat_pswpin counter\n\
+			 # HELP node_vmstat_pswpin Pages swapped in.\n\
+			 node_vmstat_pswpin_total 3\n\
+			 # TYPE node_load1 gauge\n\
+			 # HELP node_load1 1m load average.\n\
+			 node_load1 0.25\n\
+			 # EOF\n"
+		);
+	}
+}
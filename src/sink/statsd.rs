@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+use crate::prometheus::{MetricFamily, MetricType};
+use crate::sink::Sink;
+
+/// Keeps datagrams below the usual Ethernet MTU so they aren't fragmented.
+const MAX_DATAGRAM_SIZE: usize = 1432;
+
+fn sanitize(s: &str) -> String {
+	s.chars()
+		.map(|c| match c {
+			'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' => c,
+			_ => '_',
+		})
+		.collect()
+}
+
+/// Escapes a label value so it can be part of a metric name without colliding with other values.
+///
+/// Letters, digits and `-` are kept, `_` becomes `__` and any other byte becomes `_` followed by
+/// its two hex digits, e.g. `/var/lib` becomes `_2fvar_2flib`.
+fn escape_label_value(s: &str) -> String {
+	let mut escaped = String::with_capacity(s.len());
+
+	for byte in s.bytes() {
+		match byte {
+			b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' => escaped.push(byte as char),
+			b'_' => escaped.push_str("__"),
+			_ => escaped.push_str(&format!("_{:02x}", byte)),
+		}
+	}
+
+	escaped
+}
+
+/// Sends metrics to a StatsD server over UDP.
+///
+/// Plain StatsD has no labels, so label values are appended to the metric name, e.g.
+/// `node_network_receive_bytes_total{device="eth0"}` becomes
+/// `node_network_receive_bytes_total.eth0`. Values are escaped reversibly, so
+/// `node_filesystem_avail_bytes{mountpoint="/"}` becomes `node_filesystem_avail_bytes._2f` and
+/// doesn't collide with a mountpoint named `_`, which becomes `__`.
+///
+/// Counters are sent as the increase since the previous `send`, so the first `send` only records
+/// their current values.
+#[derive(Debug)]
+pub struct StatsdSink {
+	socket: UdpSocket,
+	prefix: Option<String>,
+	prev_counters: HashMap<String, f64>,
+}
+
+impl StatsdSink {
+	pub fn new<A>(addr: A) -> io::Result<StatsdSink>
+	where
+		A: ToSocketAddrs,
+	{
+		let addr = addr.to_socket_addrs()?.next().ok_or_else(|| {
+			io::Error::new(io::ErrorKind::InvalidInput, "no address to send metrics to")
+		})?;
+		let local: SocketAddr = if addr.is_ipv4() {
+			([0, 0, 0, 0], 0).into()
+		} else {
+			([0u16; 8], 0).into()
+		};
+
+		let socket = UdpSocket::bind(local)?;
+		socket.connect(addr)?;
+
+		Ok(StatsdSink {
+			socket,
+			prefix: None,
+			prev_counters: HashMap::new(),
+		})
+	}
+
+	/// Prepends `prefix.` to every metric name.
+	pub fn with_prefix<S>(mut self, prefix: S) -> StatsdSink
+	where
+		S: Into<String>,
+	{
+		self.prefix = Some(prefix.into());
+		self
+	}
+
+	fn metric_name(&self, family: &MetricFamily, labels: &[(String, String)]) -> String {
+		let mut parts: Vec<String> = self.prefix.iter().cloned().collect();
+		parts.push(sanitize(family.name()));
+		parts.extend(
+			labels
+				.iter()
+				.filter(|(_, value)| !value.is_empty())
+				.map(|(_, value)| escape_label_value(value)),
+		);
+
+		parts.join(".")
+	}
+
+	fn lines(&mut self, families: &[MetricFamily]) -> Vec<String> {
+		let mut lines = Vec::new();
+
+		for family in families {
+			for sample in family.samples() {
+				let name = self.metric_name(family, sample.labels());
+				let value = sample.value();
+
+				match family.metric_type() {
+					MetricType::Counter => {
+						if let Some(prev) = self.prev_counters.insert(name.clone(), value) {
+							// a decrease means the counter was reset
+							let delta = if value >= prev { value - prev } else { value };
+							lines.push(format!("{}:{}|c", name, delta));
+						}
+					}
+					MetricType::Gauge => {
+						// a signed gauge value is interpreted as a change, so reset to 0 first
+						if value < 0.0 {
+							lines.push(format!("{}:0|g", name));
+						}
+						lines.push(format!("{}:{}|g", name, value));
+					}
+				}
+			}
+		}
+
+		lines
+	}
+}
+
+impl Sink for StatsdSink {
+	fn send(&mut self, families: &[MetricFamily]) -> io::Result<()> {
+		let mut datagram = String::new();
+
+		for line in self.lines(families) {
+			if !datagram.is_empty() && datagram.len() + 1 + line.len() > MAX_DATAGRAM_SIZE {
+				self.socket.send(datagram.as_bytes())?;
+				datagram.clear();
+			}
+			if !datagram.is_empty() {
+				datagram.push('\n');
+			}
+			datagram.push_str(&line);
+		}
+
+		if !datagram.is_empty() {
+			self.socket.send(datagram.as_bytes())?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod unit_tests {
+	use super::*;
+	use std::time::Duration;
+
+	fn receive(server: &UdpSocket) -> String {
+		let mut buf = [0; MAX_DATAGRAM_SIZE];
+		let len = server.recv(&mut buf).unwrap();
+		String::from_utf8_lossy(&buf[..len]).into_owned()
+	}
+
+	#[test]
+	fn test_escape_label_value() {
+		assert_eq!(escape_label_value("eth0"), "eth0");
+		assert_eq!(escape_label_value("/"), "_2f");
+		assert_eq!(escape_label_value("_"), "__");
+		assert_eq!(escape_label_value("/var/lib_x"), "_2fvar_2flib__x");
+		assert_eq!(escape_label_value("é"), "_c3_a9");
+	}
+
+	#[test]
+	fn test_statsd_sink() {
+		let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+		server
+			.set_read_timeout(Some(Duration::from_secs(5)))
+			.unwrap();
+		let mut sink = StatsdSink::new(server.local_addr().unwrap())
+			.unwrap()
+			.with_prefix("host");
+
+		let families = |bytes: f64| {
+			vec![
+				MetricFamily::new("node_load1", "", MetricType::Gauge).sample(&[], 0.5),
+				MetricFamily::new("node_network_receive_bytes_total", "", MetricType::Counter)
+					.sample(&[("device", "eth0")], bytes),
+			]
+		};
+
+		sink.send(&families(100.0)).unwrap();
+		assert_eq!(receive(&server), "host.node_load1:0.5|g");
+
+		sink.send(&families(150.0)).unwrap();
+		assert_eq!(
+			receive(&server),
+			"host.node_load1:0.5|g\nhost.node_network_receive_bytes_total.eth0:50|c"
+		);
+	}
+}
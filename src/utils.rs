@@ -14,3 +14,33 @@ pub(crate) fn duration_percent(lhs: Duration, rhs: Duration) -> Percent {
 pub(crate) fn u64_percent(lhs: u64, rhs: u64) -> Percent {
 	((lhs as f64 / rhs as f64) * 100.0) as f32
 }
+
+/// Undoes the octal escaping of whitespace and backslashes in procfs paths, e.g. `\040` for a
+/// space. Invalid escape sequences are kept as is.
+#[cfg(all(target_os = "linux", any(feature = "disk", feature = "memory")))]
+pub(crate) fn unescape_octal(s: &str) -> String {
+	let bytes = s.as_bytes();
+	let mut unescaped = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+
+	while i < bytes.len() {
+		let escaped = bytes
+			.get(i + 1..i + 4)
+			.filter(|_| bytes[i] == b'\\')
+			.and_then(|digits| std::str::from_utf8(digits).ok())
+			.and_then(|digits| u8::from_str_radix(digits, 8).ok());
+
+		match escaped {
+			Some(byte) => {
+				unescaped.push(byte);
+				i += 4;
+			}
+			None => {
+				unescaped.push(bytes[i]);
+				i += 1;
+			}
+		}
+	}
+
+	String::from_utf8_lossy(&unescaped).into_owned()
+}